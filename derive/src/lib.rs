@@ -1,37 +1,144 @@
 extern crate proc_macro;
 
-use proc_macro2::TokenStream;
+use proc_macro2::{TokenStream, TokenTree};
 use proc_macro2::Ident;
 use proc_macro_error2::{abort, proc_macro_error};
-use quote::quote;
+use quote::{quote, ToTokens};
 use syn::{
     AngleBracketedGenericArguments,
     AttrStyle::Outer,
     Attribute, DeriveInput,
+    Generics,
     Expr::Lit,
-    ExprLit, Field, Fields,
+    ExprLit, Field, Fields, FieldsUnnamed,
     Fields::Named,
     GenericArgument,
     Lit::Str,
+    LitStr,
     Meta::{List, NameValue},
-    MetaList, MetaNameValue, PathArguments, PathSegment, Result, Type, TypePath,
+    MetaList, MetaNameValue, PathArguments, PathSegment, Result, Type, TypePath, TypeReference,
+    TypeArray, TypeSlice, TypeTuple, Variant,
 };
 mod case;
 
+/// the max length of a doc placed after the value with `#[toml_example(inline_doc)]`
+const INLINE_DOC_MAX_LEN: usize = 60;
+
 struct Intermediate {
     struct_name: Ident,
+    generics: Generics,
     struct_doc: String,
     field_example: String,
+    inline_example: Option<String>,
+    keys: Option<String>,
+    /// the default instance of the struct, ex: `<Self as Default>::default()`
+    default_instance: Option<String>,
+    /// the schema version of the config, which is the first comment of the example
+    version: Option<String>,
+    /// the expression whether the example has section headers, ex: `true`,
+    /// `<Inner as toml_example::TomlExample>::HAS_SECTIONS` for a flattened struct
+    has_sections: String,
+    /// the types of the inline fields, which are checked without sections at compile time
+    inline_types: Vec<String>,
 }
 
 struct FieldMeta {
     docs: Vec<String>,
+    notes: Vec<String>,
+    title: Option<String>,
+    section_name: Option<String>,
+    /// the curated body of a nesting section, which replaces the generated one
+    section_example: Option<String>,
+    /// the comment above the header of a nesting map, which explains the placeholder key
+    map_key_comment: Option<String>,
     default_source: Option<DefaultSource>,
+    /// `#[toml_example(default)]` on a struct, the fields are rendered from the default instance
+    inherit_default: bool,
     nesting_format: Option<NestingFormat>,
     require: bool,
+    show_none: bool,
+    commented: bool,
+    /// a nesting array is rendered as a commented template without any active entry
+    empty_template: bool,
+    /// the dots of the key of a map are replaced by dashes, ex: `http.01` is `http-01`
+    dash_key: bool,
+    is_enum: bool,
+    /// the representative variant of an enum field, ex: `first = Fast`
+    first: Option<String>,
+    inline_doc: bool,
+    base64: bool,
+    duration_secs: bool,
+    /// the type is serialized as a string, ex: with `FromStr` and `Display`
+    as_string: bool,
+    /// `#[serde(deserialize_with = "path")]` or `#[serde(with = "module")]`, the value in the file
+    /// may be in any type
+    custom_deserialize: bool,
+    element: Option<String>,
+    /// the variants of an enum field with the descriptions, ex: `variants = [A = "desc", B]`
+    variants: Vec<(String, Option<String>)>,
+    flatten: bool,
     skip: bool,
+    /// `#[serde(skip_serializing)]`, the field is skipped if it can be absent in the file
+    skip_serializing: bool,
+    /// `#[serde(default)]` or `#[serde(default = "path")]` on the field
+    serde_default: bool,
     rename: Option<String>,
+    /// `#[serde(deny_unknown_fields)]` on the struct
+    deny_unknown_fields: bool,
+    enum_variants_as_sections: bool,
+    enum_as_int: bool,
+    type_hints: bool,
+    /// the rust type of each field is commented above the field, which is for debugging
+    show_rust_type: bool,
+    strict: bool,
+    audit: bool,
+    all_required: bool,
+    /// the nesting sections are placed in the order of the fields, instead of the bottom
+    sections_inline: bool,
+    /// the keys are padded, such that the `=` of the keys are aligned
+    align: bool,
+    /// the keys of the nesting sections are indented with a tab
+    indent_tabs: bool,
+    tag: Option<String>,
+    content: Option<String>,
     rename_rule: case::RenameRule,
+    array_style: ArrayStyle,
+    width: Option<usize>,
+    kv_separator: String,
+    /// the schema version on the struct, ex: `version = "1.2"`
+    version: Option<String>,
+}
+
+struct ParsedField {
+    default: DefaultSource,
+    docs: Vec<String>,
+    notes: Vec<String>,
+    title: Option<String>,
+    section_name: Option<String>,
+    section_example: Option<String>,
+    map_key_comment: Option<String>,
+    optional: bool,
+    nesting_format: Option<NestingFormat>,
+    show_none: bool,
+    inline_doc: bool,
+    base64: bool,
+    duration_secs: bool,
+    as_string: bool,
+    width: Option<usize>,
+    /// the placeholder of an unknown type is rendered, which may not be deserialized
+    unknown_placeholder: bool,
+    /// the default is given by attributes
+    has_default: bool,
+    /// the array is commented out by `empty_template`, which is missing without `#[serde(default)]`
+    empty_template: bool,
+    /// the field is commented out by `commented`, which is missing without `#[serde(default)]`
+    commented: bool,
+    /// the field is given by `#[serde(default)]`
+    serde_default: bool,
+    dash_key: bool,
+    flatten: bool,
+    skip: bool,
+    rename: Option<String>,
 }
 
 #[derive(Debug)]
@@ -40,6 +147,17 @@ enum DefaultSource {
     DefaultFn(Option<String>),
     #[allow(dead_code)]
     SerdeDefaultFn(String),
+    /// a path of a const, which is evaluated in the impl, ex: `Self::DEFAULT_PORT`, `u16::MAX`
+    ConstPath(String),
+}
+
+#[derive(Clone, Copy, Default, PartialEq)]
+enum ArrayStyle {
+    /// `[ 0, 1, ]`
+    #[default]
+    Spaced,
+    /// `[0, 1]`
+    Compact,
 }
 
 #[derive(PartialEq)]
@@ -47,12 +165,131 @@ enum NestingType {
     None,
     Vec,
     Dict,
+    /// a map of arrays, ex: `HashMap<String, Vec<T>>`
+    DictVec,
+    /// an array of maps, ex: `Vec<HashMap<String, T>>`
+    VecDict,
 }
 
 #[derive(PartialEq)]
 enum NestingFormat {
     Section(NestingType),
     Prefix,
+    /// the value is the inline example of the inner structure
+    Inline(NestingType),
+}
+
+/// return the inner type of Option, or the type itself
+fn unwrap_option(ty: &Type) -> &Type {
+    if let Type::Path(TypePath { path, .. }) = ty {
+        if let Some(PathSegment {
+            ident,
+            arguments:
+                PathArguments::AngleBracketed(AngleBracketedGenericArguments { args, .. }),
+        }) = path.segments.last()
+        {
+            if ident == "Option" {
+                if let Some(GenericArgument::Type(ty)) = args.first() {
+                    return unwrap_option(ty);
+                }
+            }
+        }
+    }
+    ty
+}
+
+/// the type is `Option`, ex: `Option<T>`
+fn is_option(ty: &Type) -> bool {
+    !std::ptr::eq(unwrap_option(ty), ty)
+}
+
+/// return the placeholder of the value of a map, which may be wrapped in Option
+fn map_value_default(ty: &Type) -> Option<String> {
+    if let Type::Path(TypePath { path, .. }) = unwrap_option(ty) {
+        if let Some(PathSegment {
+            ident,
+            arguments:
+                PathArguments::AngleBracketed(AngleBracketedGenericArguments { args, .. }),
+        }) = path.segments.last()
+        {
+            if ident == "HashMap" || ident == "BTreeMap" {
+                if let Some(GenericArgument::Type(ty)) = args.last() {
+                    let mut default = String::new();
+                    parse_type(ty, &mut default, &mut false, &mut None);
+                    return Some(default);
+                }
+            }
+        }
+    }
+    None
+}
+
+/// the field is a range, which may be wrapped in Option
+fn is_range(ty: &Type) -> bool {
+    if let Type::Path(TypePath { path, .. }) = unwrap_option(ty) {
+        if let Some(PathSegment { ident, .. }) = path.segments.last() {
+            return ident == "Range" || ident == "RangeInclusive";
+        }
+    }
+    false
+}
+
+/// the field is `Bytes` or `BytesMut`, which is not wrapped in Option
+fn is_bytes(ty: &Type) -> bool {
+    if let Type::Path(TypePath { path, .. }) = ty {
+        if let Some(PathSegment { ident, .. }) = path.segments.last() {
+            return cfg!(feature = "bytes") && (ident == "Bytes" || ident == "BytesMut");
+        }
+    }
+    false
+}
+
+/// return the key type of a map, which may be wrapped in Option
+fn map_key_type(ty: &Type) -> Option<String> {
+    if let Type::Path(TypePath { path, .. }) = unwrap_option(ty) {
+        if let Some(PathSegment {
+            ident,
+            arguments:
+                PathArguments::AngleBracketed(AngleBracketedGenericArguments { args, .. }),
+        }) = path.segments.last()
+        {
+            if ident == "HashMap" || ident == "BTreeMap" {
+                if let Some(GenericArgument::Type(Type::Path(TypePath { path, .. }))) = args.first() {
+                    return path.segments.last().map(|s| s.ident.to_string());
+                }
+            }
+        }
+    }
+    None
+}
+
+fn is_phantom_data(ty: &Type) -> bool {
+    if let Type::Path(TypePath { path, .. }) = ty {
+        path.segments
+            .last()
+            .map(|s| s.ident == "PhantomData")
+            .unwrap_or_default()
+    } else {
+        false
+    }
+}
+
+/// check the type param is used in the tokens of a type
+fn uses_type_param(tokens: TokenStream, param: &Ident) -> bool {
+    tokens.into_iter().any(|t| match t {
+        TokenTree::Ident(ident) => &ident == param,
+        TokenTree::Group(group) => uses_type_param(group.stream(), param),
+        _ => false,
+    })
+}
+
+/// the types which `default_value` gives a placeholder can be deserialized
+fn is_known_type(ty: &str) -> bool {
+    matches!(
+        ty,
+        "usize" | "u8" | "u16" | "u32" | "u64" | "u128" | "isize" | "i8" | "i16" | "i32"
+            | "i64" | "i128" | "f32" | "f64" | "bool" | "String" | "str" | "PathBuf"
+    ) || cfg!(feature = "bytes") && matches!(ty, "Bytes" | "BytesMut")
 }
 
 fn default_value(ty: String) -> String {
@@ -60,12 +297,13 @@ fn default_value(ty: String) -> String {
         "usize" | "u8" | "u16" | "u32" | "u64" | "u128" | "isize" | "i8" | "i16" | "i32"
         | "i64" | "i128" => "0",
         "f32" | "f64" => "0.0",
+        "bool" => "false",
         _ => "\"\"",
     }
     .to_string()
 }
 
-/// return type without Option, Vec
+/// return type without Option, Vec, references and transparent wrappers
 fn parse_type(
     ty: &Type,
     default: &mut String,
@@ -86,25 +324,54 @@ fn parse_type(
                 }) = arguments
                 {
                     if let Some(GenericArgument::Type(ty)) = args.first() {
-                        r#type = parse_type(ty, default, &mut false, nesting_format);
+                        // Option<Option<T>> is still an optional field
+                        r#type = parse_type(ty, default, optional, nesting_format);
                     }
                 }
-            } else if id == "Vec" {
-                if nesting_format.is_some() {
-                    *nesting_format = Some(NestingFormat::Section(NestingType::Vec));
+            } else if matches!(
+                id.as_str(),
+                "Box" | "Rc" | "Arc" | "Cell" | "RefCell" | "Mutex" | "RwLock"
+            ) {
+                // smart pointers and interior mutability wrappers are transparent in toml
+                if let PathArguments::AngleBracketed(AngleBracketedGenericArguments {
+                    args, ..
+                }) = arguments
+                {
+                    if let Some(GenericArgument::Type(ty)) = args.first() {
+                        r#type = parse_type(ty, default, optional, nesting_format);
+                    }
                 }
+            } else if cfg!(feature = "arrayvec") && id == "ArrayString" {
+                // the capacity of `ArrayString<N>` is not a part of the placeholder
+                r#type = Some("String".to_string());
+                *default = default_value("String".to_string());
+            } else if id == "Range" || id == "RangeInclusive" {
+                // serde serializes a range as a table of the bounds
                 if let PathArguments::AngleBracketed(AngleBracketedGenericArguments {
                     args, ..
                 }) = arguments
                 {
                     if let Some(GenericArgument::Type(ty)) = args.first() {
-                        let mut item_default_value = String::new();
-                        r#type = parse_type(ty, &mut item_default_value, &mut false, &mut None);
-                        *default = if item_default_value.is_empty() {
-                            "[  ]".to_string()
-                        } else {
-                            format!("[ {item_default_value:}, ]")
-                        }
+                        let mut bound = String::new();
+                        r#type = parse_type(ty, &mut bound, &mut false, &mut None);
+                        *default = format!("{{ start = {bound}, end = {bound} }}");
+                    }
+                }
+            } else if id == "Vec"
+                || cfg!(feature = "smallvec") && id == "SmallVec"
+                || cfg!(feature = "arrayvec") && id == "ArrayVec"
+            {
+                if let PathArguments::AngleBracketed(AngleBracketedGenericArguments {
+                    args, ..
+                }) = arguments
+                {
+                    if let Some(GenericArgument::Type(ty)) = args.first() {
+                        // the item of `SmallVec<[T; N]>` or `ArrayVec<[T; N]>` is the item of the inline array
+                        let ty = match ty {
+                            Type::Array(TypeArray { elem, .. }) if id != "Vec" => elem,
+                            _ => ty,
+                        };
+                        r#type = parse_array_type(ty, default, nesting_format);
                     }
                 }
             } else if id == "HashMap" || id == "BTreeMap" {
@@ -114,29 +381,252 @@ fn parse_type(
                 {
                     if let Some(GenericArgument::Type(ty)) = args.last() {
                         let mut item_default_value = String::new();
-                        r#type = parse_type(ty, &mut item_default_value, &mut false, &mut None);
+                        let mut item_nesting_format = Some(NestingFormat::Section(NestingType::None));
+                        r#type = parse_type(
+                            ty,
+                            &mut item_default_value,
+                            &mut false,
+                            &mut item_nesting_format,
+                        );
+                        if nesting_format.is_none() {
+                            // a map without nesting is an inline table
+                            *default = format!("{{ example = {item_default_value} }}");
+                        }
+                        if item_nesting_format == Some(NestingFormat::Section(NestingType::Vec)) {
+                            set_nesting_type(nesting_format, NestingType::DictVec);
+                        } else {
+                            set_nesting_type(nesting_format, NestingType::Dict);
+                        }
                     }
                 }
-                if nesting_format.is_some() {
-                    *nesting_format = Some(NestingFormat::Section(NestingType::Dict));
-                }
             }
             // TODO else Complex struct in else
         }
+    } else if let Type::Slice(TypeSlice { elem, .. }) = ty {
+        r#type = parse_array_type(elem, default, nesting_format);
+    } else if let Type::Array(TypeArray { elem, len, .. }) = ty {
+        r#type = parse_array_type(elem, default, nesting_format);
+        // a fixed size array only deserializes from the same number of items
+        if let Some(n) = syn::parse2::<syn::LitInt>(len.to_token_stream())
+            .ok()
+            .and_then(|n| n.base10_parse::<usize>().ok())
+        {
+            let item = default
+                .strip_prefix("[ ")
+                .and_then(|d| d.strip_suffix(", ]"))
+                .unwrap_or_default()
+                .to_string();
+            *default = if n == 0 || item.is_empty() {
+                "[ ]".to_string()
+            } else {
+                format!("[ {}, ]", vec![item; n].join(", "))
+            };
+        }
+    } else if let Type::Reference(TypeReference { elem, .. }) = ty {
+        r#type = parse_type(elem, default, optional, nesting_format);
+    } else if let Type::Tuple(TypeTuple { elems, .. }) = ty {
+        // serde serializes a tuple as an array
+        let items: Vec<String> = elems
+            .iter()
+            .map(|ty| {
+                let mut item_default_value = String::new();
+                parse_type(ty, &mut item_default_value, &mut false, &mut None);
+                item_default_value
+            })
+            .collect();
+        *default = format!("[ {}, ]", items.join(", "));
     }
     r#type
 }
 
+/// return the path of the type without Option, Vec, references and transparent wrappers, which is
+/// used to call the methods of the type, ex: `legacy::Config` of `Option<Box<legacy::Config>>`
+fn type_path(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Path(TypePath { path, .. }) => {
+            let PathSegment { ident, arguments } = path.segments.last()?;
+            let id = ident.to_string();
+            if let PathArguments::AngleBracketed(AngleBracketedGenericArguments { args, .. }) = arguments {
+                let wrapper = matches!(
+                    id.as_str(),
+                    "Option" | "Box" | "Rc" | "Arc" | "Cell" | "RefCell" | "Mutex" | "RwLock" | "Vec"
+                        | "SmallVec" | "ArrayVec" | "HashMap" | "BTreeMap" | "Range" | "RangeInclusive"
+                );
+                if wrapper {
+                    // the value of a map is the last argument
+                    let arg = if id == "HashMap" || id == "BTreeMap" { args.last() } else { args.first() };
+                    return match arg {
+                        Some(GenericArgument::Type(Type::Array(TypeArray { elem, .. }))) if id != "Vec" => {
+                            type_path(elem)
+                        }
+                        Some(GenericArgument::Type(ty)) => type_path(ty),
+                        _ => None,
+                    };
+                }
+            }
+            // the generic arguments are inferred
+            let segments: Vec<String> = path.segments.iter().map(|s| s.ident.to_string()).collect();
+            let leading_colon = if path.leading_colon.is_some() { "::" } else { "" };
+            Some(format!("{leading_colon}{}", segments.join("::")))
+        }
+        Type::Slice(TypeSlice { elem, .. })
+        | Type::Array(TypeArray { elem, .. })
+        | Type::Reference(TypeReference { elem, .. }) => type_path(elem),
+        _ => None,
+    }
+}
+
+/// the toml type of a rust type, ex: `integer`, `array of strings`
+fn type_hint(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Path(TypePath { path, .. }) => {
+            let PathSegment { ident, arguments } = path.segments.last()?;
+            let inner = if let PathArguments::AngleBracketed(AngleBracketedGenericArguments {
+                args,
+                ..
+            }) = arguments
+            {
+                args.iter().rev().find_map(|a| match a {
+                    GenericArgument::Type(ty) => Some(ty),
+                    _ => None,
+                })
+            } else {
+                None
+            };
+            match ident.to_string().as_str() {
+                "usize" | "u8" | "u16" | "u32" | "u64" | "u128" | "isize" | "i8" | "i16" | "i32"
+                | "i64" | "i128" => Some("integer".into()),
+                "f32" | "f64" => Some("float".into()),
+                "bool" => Some("boolean".into()),
+                "String" | "str" | "char" | "PathBuf" => Some("string".into()),
+                "Option" | "Box" | "Rc" | "Arc" | "Cell" | "RefCell" | "Mutex" | "RwLock" => {
+                    type_hint(inner?)
+                }
+                "Vec" | "HashSet" | "BTreeSet" => Some(array_hint(inner.and_then(type_hint))),
+                // the hint of `SmallVec<[T; N]>` is the hint of the inline array
+                "SmallVec" if cfg!(feature = "smallvec") => type_hint(inner?),
+                "ArrayVec" if cfg!(feature = "arrayvec") => match inner? {
+                    ty @ Type::Array(_) => type_hint(ty),
+                    ty => Some(array_hint(type_hint(ty))),
+                },
+                "ArrayString" if cfg!(feature = "arrayvec") => Some("string".into()),
+                "Bytes" | "BytesMut" if cfg!(feature = "bytes") => Some("string".into()),
+                "HashMap" | "BTreeMap" | "Range" | "RangeInclusive" => Some("table".into()),
+                _ => None,
+            }
+        }
+        Type::Slice(TypeSlice { elem, .. }) | Type::Array(TypeArray { elem, .. }) => {
+            Some(array_hint(type_hint(elem)))
+        }
+        Type::Reference(TypeReference { elem, .. }) => type_hint(elem),
+        Type::Tuple(_) => Some("array".into()),
+        _ => None,
+    }
+}
+
+/// The rust type as it is written in the struct, ex: `Option<Vec<String>>`
+fn rust_type_name(ty: &Type) -> String {
+    ty.to_token_stream()
+        .to_string()
+        .replace(" <", "<")
+        .replace("< ", "<")
+        .replace(" >", ">")
+        .replace(" ,", ",")
+        .replace(" ;", ";")
+        .replace(" :: ", "::")
+        .replace(":: ", "::")
+        .replace("& ", "&")
+}
+
+fn array_hint(item_hint: Option<String>) -> String {
+    match item_hint {
+        Some(hint) if !hint.starts_with("array") => format!("array of {hint}s"),
+        _ => "array".into(),
+    }
+}
+
+fn set_nesting_type(nesting_format: &mut Option<NestingFormat>, nesting_type: NestingType) {
+    match nesting_format {
+        Some(NestingFormat::Inline(_)) => *nesting_format = Some(NestingFormat::Inline(nesting_type)),
+        Some(_) => *nesting_format = Some(NestingFormat::Section(nesting_type)),
+        None => (),
+    }
+}
+
+/// return item type of an array, and the default value of the array
+fn parse_array_type(
+    ty: &Type,
+    default: &mut String,
+    nesting_format: &mut Option<NestingFormat>,
+) -> Option<String> {
+    let mut item_default_value = String::new();
+    // the item is nested only if the array is, an array of maps without nesting is inline tables
+    let mut item_nesting_format = nesting_format
+        .as_ref()
+        .map(|_| NestingFormat::Section(NestingType::None));
+    let r#type = parse_type(ty, &mut item_default_value, &mut false, &mut item_nesting_format);
+    if item_nesting_format == Some(NestingFormat::Section(NestingType::Dict)) {
+        set_nesting_type(nesting_format, NestingType::VecDict);
+    } else {
+        set_nesting_type(nesting_format, NestingType::Vec);
+    }
+    *default = if item_default_value.is_empty() {
+        "[  ]".to_string()
+    } else {
+        format!("[ {item_default_value:}, ]")
+    };
+    r#type
+}
+
 fn parse_attrs(
     attrs: &[Attribute],
 ) -> FieldMeta {
     let mut docs = Vec::new();
+    let mut notes = Vec::new();
+    let mut title = None;
+    let mut section_name = None;
+    let mut section_example = None;
+    let mut map_key_comment = None;
     let mut default_source = None;
+    let mut inherit_default = false;
     let mut nesting_format = None;
     let mut require = false;
+    let mut show_none = false;
+    let mut commented = false;
+    let mut empty_template = false;
+    let mut dash_key = false;
+    let mut is_enum = false;
+    let mut first = None;
+    let mut inline_doc = false;
+    let mut base64 = false;
+    let mut duration_secs = false;
+    let mut as_string = false;
+    let mut custom_deserialize = false;
+    let mut element = None;
+    let mut variants = Vec::new();
+    let mut flatten = false;
     let mut skip = false;
+    let mut skip_serializing = false;
+    let mut serde_default = false;
     let mut rename = None;
     let mut rename_rule = case::RenameRule::None;
+    let mut array_style = ArrayStyle::default();
+    let mut width = None;
+    let mut kv_separator = " = ".to_string();
+    let mut version = None;
+    let mut enum_variants_as_sections = false;
+    let mut enum_as_int = false;
+    let mut type_hints = false;
+    let mut show_rust_type = false;
+    let mut strict = false;
+    let mut audit = false;
+    let mut all_required = false;
+    let mut sections_inline = false;
+    let mut align = false;
+    let mut indent_tabs = false;
+    let mut deny_unknown_fields = false;
+    let mut tag = None;
+    let mut content = None;
 
     for attr in attrs.iter() {
         match (attr.style, &attr.meta) {
@@ -168,31 +658,60 @@ fn parse_attrs(
                 #[cfg(feature = "serde")]
                 {
                     let token_str = _tokens.to_string();
-                    if token_str.starts_with("default") {
-                        if let Some((_, s)) = token_str.split_once('=') {
-                            default_source = Some(DefaultSource::SerdeDefaultFn(
-                                s.trim().trim_matches('"').into(),
-                            ));
-                        } else {
-                            default_source = Some(DefaultSource::DefaultFn(None));
-                        }
-                    }
-                    if token_str == "skip_deserializing" || token_str == "skip" {
-                        skip = true;
-                    }
-                    if token_str.starts_with("rename") {
-                        if token_str.starts_with("rename_all") {
-                            if let Some((_, s)) = token_str.split_once('=') {
+                    for item in split_unenclosed(&token_str, ',') {
+                        match split_key_value(item) {
+                            ("default", Some(s)) => {
+                                default_source = Some(DefaultSource::SerdeDefaultFn(
+                                    s.trim_matches('"').into(),
+                                ));
+                                serde_default = true;
+                            }
+                            ("default", None) => {
+                                default_source = Some(DefaultSource::DefaultFn(None));
+                                serde_default = true;
+                            }
+                            ("skip_deserializing", None) | ("skip", None) => skip = true,
+                            ("skip_serializing", None) => skip_serializing = true,
+                            ("flatten", None) => flatten = true,
+                            ("deserialize_with", Some(_)) | ("with", Some(_)) => custom_deserialize = true,
+                            ("deny_unknown_fields", None) => deny_unknown_fields = true,
+                            ("rename_all", Some(s)) => {
                                 rename_rule = if let Ok(r) =
-                                    case::RenameRule::from_str(s.trim().trim_matches('"'))
+                                    case::RenameRule::from_str(s.trim_matches('"'))
                                 {
                                     r
                                 } else {
                                     abort!(&_tokens, "unsupported rename rule")
                                 }
                             }
-                        } else if let Some((_, s)) = token_str.split_once('=') {
-                            rename = Some(s.trim().trim_matches('"').into());
+                            ("rename", Some(s)) => rename = Some(s.trim_matches('"').into()),
+                            ("tag", Some(s)) => tag = Some(s.trim_matches('"').into()),
+                            ("content", Some(s)) => content = Some(s.trim_matches('"').into()),
+                            _ => (),
+                        }
+                    }
+                }
+            }
+            (
+                Outer,
+                List(MetaList {
+                    path,
+                    tokens: _tokens,
+                    ..
+                }),
+            ) if path
+                .segments
+                .last()
+                .map(|s| s.ident == "validate")
+                .unwrap_or_default() =>
+            {
+                // the constraints of the validator crate are listed below the docs
+                #[cfg(feature = "validator")]
+                {
+                    let token_str = _tokens.to_string();
+                    for item in split_unenclosed(&token_str, ',') {
+                        if let Some(constraint) = validate_constraint(item) {
+                            docs.push(format!(" {constraint}"));
                         }
                     }
                 }
@@ -205,28 +724,93 @@ fn parse_attrs(
                     .unwrap_or_default() =>
             {
                 let token_str = tokens.to_string();
-                if token_str.starts_with("default") {
-                    if let Some((_, s)) = token_str.split_once('=') {
-                        default_source = Some(DefaultSource::DefaultValue(s.trim().into()));
-                    } else {
-                        default_source = Some(DefaultSource::DefaultFn(None));
-                    }
-                } else if token_str.starts_with("nesting") {
-                    if let Some((_, s)) = token_str.split_once('=') {
-                        nesting_format = match s.trim() {
-                            "prefix" => Some(NestingFormat::Prefix),
-                            "section" => Some(NestingFormat::Section(NestingType::None)),
-                            _ => abort!(&attr, "please use prefix or section for nesting derive"),
+                for item in split_unenclosed(&token_str, ',') {
+                    match split_key_value(item) {
+                        ("default", Some(s))
+                            if s.contains("::") && syn::parse_str::<syn::ExprPath>(s).is_ok() =>
+                        {
+                            default_source = Some(DefaultSource::ConstPath(s.replace(' ', "")));
                         }
-                    } else {
-                        nesting_format = Some(NestingFormat::Section(NestingType::None));
+                        ("default", Some(s)) => {
+                            default_source = Some(DefaultSource::DefaultValue(s.into()));
+                        }
+                        ("default", None) => {
+                            // the default function of serde is the default, in any order of the attributes
+                            if !matches!(default_source, Some(DefaultSource::SerdeDefaultFn(_))) {
+                                default_source = Some(DefaultSource::DefaultFn(None));
+                            }
+                            inherit_default = true;
+                        }
+                        ("nesting", Some(s)) => {
+                            nesting_format = match s {
+                                "prefix" => Some(NestingFormat::Prefix),
+                                "section" => Some(NestingFormat::Section(NestingType::None)),
+                                "inline" => Some(NestingFormat::Inline(NestingType::None)),
+                                _ => abort!(&attr, "please use prefix, section or inline for nesting derive"),
+                            }
+                        }
+                        ("nesting", None) => {
+                            nesting_format = Some(NestingFormat::Section(NestingType::None));
+                        }
+                        ("require", None) => require = true,
+                        ("show_none", None) => show_none = true,
+                        ("commented", None) => commented = true,
+                        ("empty_template", None) => empty_template = true,
+                        ("dash_key", None) => dash_key = true,
+                        ("enum", None) => is_enum = true,
+                        ("first", Some(s)) => first = Some(s.trim_matches('"').to_string()),
+                        ("inline_doc", None) => inline_doc = true,
+                        ("base64", None) => base64 = true,
+                        ("duration_secs", None) => duration_secs = true,
+                        ("as_string", None) => as_string = true,
+                        ("element", Some(s)) => element = Some(s.to_string()),
+                        ("variants", Some(s)) => {
+                            let Some(list) = s.strip_prefix('[').and_then(|s| s.strip_suffix(']')) else {
+                                abort!(&attr, "please use a list for variants, ex: `variants = [A = \"desc\", B]`")
+                            };
+                            for variant in split_unenclosed(list, ',') {
+                                let (name, desc) = split_key_value(variant);
+                                variants.push((name.to_string(), desc.map(|d| parse_lit_str(attr, d))));
+                            }
+                        }
+                        ("note", Some(s)) => notes.push(parse_lit_str(attr, s)),
+                        // documentary only, the condition is not validated
+                        ("required_with", Some(s)) => {
+                            docs.push(format!(" required when {} is set", parse_lit_str(attr, s)))
+                        }
+                        ("title", Some(s)) => title = Some(parse_lit_str(attr, s)),
+                        ("section_name", Some(s)) => section_name = Some(parse_lit_str(attr, s)),
+                        ("section_example", Some(s)) => section_example = Some(parse_lit_str(attr, s)),
+                        ("map_key_comment", Some(s)) => map_key_comment = Some(parse_lit_str(attr, s)),
+                        ("version", Some(s)) => version = Some(parse_lit_str(attr, s)),
+                        ("skip", None) | ("skip_serializing", None) => skip = true,
+                        ("flatten", None) => flatten = true,
+                        ("array_style", Some(s)) => {
+                            array_style = match s.trim_matches('"') {
+                                "spaced" => ArrayStyle::Spaced,
+                                "compact" => ArrayStyle::Compact,
+                                _ => abort!(&attr, "please use spaced or compact for array_style"),
+                            }
+                        }
+                        ("width", Some(s)) => {
+                            width = match s.parse() {
+                                Ok(w) => Some(w),
+                                _ => abort!(&attr, "please use a number for width"),
+                            }
+                        }
+                        ("kv_separator", Some(s)) => kv_separator = parse_lit_str(attr, s),
+                        ("enum_variants_as_sections", None) => enum_variants_as_sections = true,
+                        ("enum_as_int", None) => enum_as_int = true,
+                        ("type_hints", None) => type_hints = true,
+                        ("show_rust_type", None) => show_rust_type = true,
+                        ("strict", None) => strict = true,
+                        ("audit", None) => audit = true,
+                        ("all_required", None) => all_required = true,
+                        ("sections_inline", None) => sections_inline = true,
+                        ("align", None) => align = true,
+                        ("indent_tabs", None) => indent_tabs = true,
+                        _ => abort!(&attr, format!("{} is not allowed attribute", item)),
                     }
-                } else if token_str == "require" {
-                    require = true;
-                } else if token_str == "skip" {
-                    skip = true;
-                } else {
-                    abort!(&attr, format!("{} is not allowed attribute", token_str))
                 }
             }
             _ => (),
@@ -235,63 +819,556 @@ fn parse_attrs(
 
     FieldMeta{
         docs,
+        notes,
+        title,
+        section_name,
+        section_example,
+        map_key_comment,
         default_source,
+        inherit_default,
         nesting_format,
         require,
+        show_none,
+        commented,
+        empty_template,
+        dash_key,
+        is_enum,
+        first,
+        inline_doc,
+        base64,
+        duration_secs,
+        as_string,
+        custom_deserialize,
+        element,
+        variants,
+        flatten,
         skip,
+        skip_serializing,
+        serde_default,
         rename,
+        deny_unknown_fields,
+        enum_variants_as_sections,
+        enum_as_int,
+        type_hints,
+        show_rust_type,
+        strict,
+        audit,
+        all_required,
+        sections_inline,
+        align,
+        indent_tabs,
+        tag,
+        content,
         rename_rule,
+        array_style,
+        width,
+        kv_separator,
+        version,
     }
 }
 
-fn parse_field(
-    field: &Field,
-) -> (
-    DefaultSource,
-    Vec<String>,
-    bool,
-    Option<NestingFormat>,
-    bool,
-    Option<String>,
-) {
+fn parse_field(field: &Field) -> ParsedField {
     let mut default_value = String::new();
     let mut optional = false;
-    let FieldMeta {docs, default_source, mut nesting_format, require, skip, rename, ..} =
-        parse_attrs(&field.attrs);
+    let FieldMeta {
+        mut docs,
+        mut notes,
+        title,
+        section_name,
+        section_example,
+        map_key_comment,
+        mut default_source,
+        mut nesting_format,
+        require,
+        show_none,
+        commented,
+        empty_template,
+        dash_key,
+        is_enum,
+        first,
+        inline_doc,
+        base64,
+        duration_secs,
+        as_string,
+        custom_deserialize,
+        element,
+        variants,
+        width,
+        flatten,
+        skip,
+        skip_serializing,
+        serde_default,
+        rename,
+        ..
+    } = parse_attrs(&field.attrs);
+    // PhantomData carries no data, and serde skips it
+    let skip = skip || is_phantom_data(&field.ty);
+    // `default = None` is the absence of the value, the field is commented out with a placeholder
+    let none_default =
+        matches!(&default_source, Some(DefaultSource::DefaultValue(v)) if v == "None");
+    if none_default {
+        default_source = None;
+    }
+    // the path of a variant, ex: `Level::Low`, is the name of the variant for an enum field, as
+    // `first`, and for the key of a map, as `default = Low`
+    if let Some(DefaultSource::ConstPath(c)) = &default_source {
+        if is_enum || map_key_type(&field.ty).is_some() {
+            let variant = c.rsplit("::").next().unwrap_or_default();
+            default_source = Some(DefaultSource::DefaultValue(if is_enum {
+                format!("{variant:?}")
+            } else {
+                variant.to_string()
+            }));
+        }
+    }
+    // the named variant is rendered as it is, the enum is not required to implement `Default`
+    if let Some(first) = first {
+        if !is_enum {
+            abort!(&field.ident, "first only work on enum")
+        }
+        if default_source.is_none() {
+            default_source = Some(DefaultSource::DefaultValue(format!("{first:?}")));
+        }
+    }
+    // the default variant of an enum is rendered inline, unless the default value is given
+    if is_enum && !matches!(default_source, Some(DefaultSource::DefaultValue(_))) {
+        nesting_format = Some(NestingFormat::Inline(NestingType::None));
+    }
     let ty = parse_type(
         &field.ty,
         &mut default_value,
         &mut optional,
         &mut nesting_format,
     );
-    let default_source = match default_source {
-        Some(DefaultSource::DefaultFn(_)) => DefaultSource::DefaultFn(ty),
+    // a field skipped on serializing is still required on deserializing, unless it can be absent
+    let skip = skip || skip_serializing && (serde_default || optional);
+    if require
+        && optional
+        && !skip
+        && !none_default
+        && default_source.is_none()
+        && nesting_format.is_none()
+        && !ty.as_deref().map(is_known_type).unwrap_or_default()
+    {
+        abort!(
+            &field.ident,
+            "please provide `#[toml_example(default = ...)]` for the required field, the placeholder of the type is unknown"
+        )
+    }
+    if matches!(
+        nesting_format,
+        Some(NestingFormat::Section(
+            NestingType::Dict | NestingType::DictVec | NestingType::VecDict
+        )) | Some(NestingFormat::Inline(
+            NestingType::Dict | NestingType::DictVec | NestingType::VecDict
+        ))
+    ) && !matches!(default_source, Some(DefaultSource::DefaultValue(_)))
+    {
+        // a warning of the derive is not shown on stable, so it is a note in the example
+        if let Some(key_ty) = map_key_type(&field.ty).filter(|k| !is_known_type(k)) {
+            notes.push(format!("WARNING: the key `example` may not deserialize into `{key_ty}`"));
+        }
+    }
+    // the element of the array placeholder, ex: `element = "item"` is `[ "item", ]`
+    if let Some(element) = element {
+        if !default_value.starts_with('[') {
+            abort!(&field.ident, "element only work on array")
+        }
+        if default_source.is_none() {
+            default_source = Some(DefaultSource::DefaultValue(format!("[{element}]")));
+        }
+    }
+    // a range is a table of the bounds, ex: `default = 0..10` is `{ start = 0, end = 10 }`
+    if is_range(&field.ty) {
+        if let Some(DefaultSource::DefaultValue(v)) = &default_source {
+            let v = v.trim_matches('"');
+            if let Some((start, end)) = v.split_once("..=").or_else(|| v.split_once("..")) {
+                default_source = Some(DefaultSource::DefaultValue(format!(
+                    "{{ start = {}, end = {} }}",
+                    format_value(start, ArrayStyle::default()),
+                    format_value(end, ArrayStyle::default())
+                )));
+            }
+        }
+    }
+    // best-effort check of the default value against the type of the field, the value of a field
+    // deserialized by a custom function can be in any type
+    if let (Some(DefaultSource::DefaultValue(v)), Some(ty), None, false) =
+        (&default_source, &ty, &nesting_format, custom_deserialize || as_string)
+    {
+        let v = v.trim();
+        let is_string = v.starts_with('"') || v.starts_with("r\"") || v.starts_with("r#");
+        let is_number = matches!(
+            syn::parse_str::<syn::Lit>(v.trim_start_matches('-')),
+            Ok(syn::Lit::Int(_) | syn::Lit::Float(_))
+        );
+        if matches!(v.trim_start_matches(['+', '-']), "inf" | "nan")
+            && !matches!(ty.as_str(), "f32" | "f64")
+        {
+            abort!(
+                &field.ident,
+                "the default `{}` is only valid for float, but the type is `{}`",
+                v,
+                ty
+            )
+        }
+        let mismatched = match ty.as_str() {
+            "usize" | "u8" | "u16" | "u32" | "u64" | "u128" | "isize" | "i8" | "i16" | "i32"
+            | "i64" | "i128" | "f32" | "f64" => is_string,
+            "String" | "str" | "PathBuf" => is_number,
+            _ => false,
+        };
+        if mismatched {
+            abort!(
+                &field.ident,
+                "the default `{}` does not match the type `{}`",
+                v,
+                ty
+            )
+        }
+    }
+    // the keys of a map without nesting can be given as an array, ex: `default = [a, b]` is
+    // rendered as `{ a = 0, b = 0 }`
+    let map_default = match (&nesting_format, &default_source) {
+        (None, Some(DefaultSource::DefaultValue(v)))
+            if v.trim().starts_with('[') && v.trim().ends_with(']') =>
+        {
+            map_value_default(&field.ty).map(|item| {
+                let v = v.trim();
+                let items: Vec<String> = split_unenclosed(&v[1..v.len() - 1], ',')
+                    .into_iter()
+                    .map(|k| format!("{} = {item}", default_key(DefaultSource::DefaultValue(k.into()), dash_key)))
+                    .collect();
+                format!("{{ {} }}", items.join(", "))
+            })
+        }
+        _ => None,
+    };
+    if let Some(map_default) = map_default {
+        default_source = Some(DefaultSource::DefaultValue(map_default));
+    }
+    if base64 {
+        notes.push("base64 encoded".into());
+    }
+    if empty_template && nesting_format != Some(NestingFormat::Section(NestingType::Vec)) {
+        abort!(&field.ident, "empty_template only work on nesting array")
+    }
+    if map_key_comment.is_some()
+        && !matches!(
+            nesting_format,
+            Some(NestingFormat::Section(
+                NestingType::Dict | NestingType::DictVec | NestingType::VecDict
+            ))
+        )
+    {
+        abort!(&field.ident, "map_key_comment only work on nesting map")
+    }
+    if section_example.is_some() && !matches!(nesting_format, Some(NestingFormat::Section(_))) {
+        abort!(&field.ident, "section_example only work on nesting section")
+    }
+    // the Duration is serialized as the integer seconds, ex: `serde_with::DurationSeconds`
+    if duration_secs {
+        if ty.as_deref() != Some("Duration") {
+            abort!(&field.ident, "duration_secs only work on Duration")
+        }
+        if matches!(default_source, None | Some(DefaultSource::DefaultFn(_))) {
+            default_source = Some(DefaultSource::DefaultValue("0".into()));
+        }
+    }
+    // the value of a type serialized as a string is quoted, ex: `default = 1.2.3` is `"1.2.3"`
+    if as_string {
+        if let Some(DefaultSource::DefaultValue(v)) = &default_source {
+            if !v.starts_with(['"', '\'']) {
+                default_source = Some(DefaultSource::DefaultValue(format!("{:?}", v.replace(' ', ""))));
+            }
+        }
+    }
+    let has_default = default_source.is_some();
+    let unknown_placeholder = default_source.is_none()
+        && !as_string
+        && nesting_format.is_none()
+        && ty.as_deref().map(|t| !is_known_type(t)).unwrap_or_default();
+    // the variants are listed below the docs, ex: `# - Important: urgent tasks`
+    for (name, desc) in variants {
+        docs.push(match desc {
+            Some(desc) => format!(" - {name}: {desc}"),
+            None => format!(" - {name}"),
+        });
+    }
+    // only a single short doc line can be placed after the value
+    let inline_doc = inline_doc && docs.len() == 1 && docs[0].trim().len() <= INLINE_DOC_MAX_LEN;
+    let default = match default_source {
+        Some(DefaultSource::DefaultFn(_)) => {
+            // use the field type without Option, such that Vec<T> is rendered as an array
+            let inner_ty = unwrap_option(&field.ty);
+            DefaultSource::DefaultFn(ty.map(|_| quote!(#inner_ty).to_string()))
+        }
         Some(DefaultSource::SerdeDefaultFn(f)) => DefaultSource::SerdeDefaultFn(f),
+        Some(DefaultSource::ConstPath(c)) => DefaultSource::ConstPath(c),
         Some(DefaultSource::DefaultValue(v)) => DefaultSource::DefaultValue(v),
+        // the bytes are serialized as a base64 string
+        _ if base64 => DefaultSource::DefaultValue("\"\"".into()),
         _ => DefaultSource::DefaultValue(default_value),
     };
-    (
-        default_source,
+    ParsedField {
+        default,
         docs,
-        optional && !require,
+        notes,
+        title,
+        section_name,
+        section_example,
+        map_key_comment,
+        // a commented field is rendered as an unset optional field
+        optional: (optional && !require) || commented || none_default || empty_template,
         nesting_format,
+        show_none: show_none && optional && require,
+        inline_doc,
+        base64,
+        duration_secs,
+        as_string,
+        width,
+        unknown_placeholder,
+        has_default,
+        empty_template: empty_template && !serde_default && !optional,
+        commented: commented && !serde_default && !optional,
+        serde_default,
+        dash_key,
+        flatten,
         skip,
         rename,
-    )
+    }
+}
+
+/// Normalize the array or tuple literal in the value with the style, other values are kept as it is
+fn format_value(value: &str, style: ArrayStyle) -> String {
+    let value = value.trim();
+    // a tuple is an array in toml
+    if !(value.starts_with('[') && value.ends_with(']')
+        || value.starts_with('(') && value.ends_with(')'))
+    {
+        return strip_number_suffix(value)
+            .or_else(|| raw_string_value(value))
+            .or_else(|| char_value(value))
+            .unwrap_or_else(|| value.to_string());
+    }
+    let items: Vec<String> = split_unenclosed(&value[1..value.len() - 1], ',')
+        .into_iter()
+        .map(|i| format_value(i, style))
+        .collect();
+    match style {
+        ArrayStyle::Spaced if items.is_empty() => "[ ]".to_string(),
+        ArrayStyle::Spaced => format!("[ {}, ]", items.join(", ")),
+        ArrayStyle::Compact => format!("[{}]", items.join(", ")),
+    }
+}
+
+/// The multi-line form of an array, each item is in a line, the lines are commented if the field
+/// is optional or the prefix is a comment
+fn wrap_array(value: &str, style: ArrayStyle, optional: bool) -> Option<String> {
+    let value = value.trim();
+    if !(value.starts_with('[') && value.ends_with(']')
+        || value.starts_with('(') && value.ends_with(')'))
+    {
+        return None;
+    }
+    let comment = format!(
+        "\"##.to_string() + (if prefix.starts_with('#') {{ \"# \" }} else {{ \"\" }}) + &r##\"{}",
+        if optional { "# " } else { "" }
+    );
+    let mut wrapped = "[\n".to_string();
+    for item in split_unenclosed(&value[1..value.len() - 1], ',') {
+        wrapped.push_str(&format!("{comment}    {},\n", format_value(item, style)));
+    }
+    wrapped.push_str(&comment);
+    wrapped.push(']');
+    Some(wrapped)
+}
+
+/// A rust raw string is a literal string in toml, or an escaped basic string if the literal
+/// string can not hold it, ex: `r"C:\path"` is `'C:\path'`
+fn raw_string_value(value: &str) -> Option<String> {
+    if !value.starts_with('r') {
+        return None;
+    }
+    let value = syn::parse_str::<LitStr>(value).ok()?.value();
+    if !value.contains(['\'', '\n', '\r']) {
+        return Some(format!("'{value}'"));
+    }
+    Some(basic_string(&value))
+}
+
+/// A rust char literal is a toml string, ex: `'Z'` is `"Z"`, and `'\n'` is `"\n"`
+fn char_value(value: &str) -> Option<String> {
+    let c = syn::parse_str::<syn::LitChar>(value).ok()?.value();
+    Some(basic_string(&c.to_string()))
+}
+
+/// The escaped toml basic string of the value
+fn basic_string(value: &str) -> String {
+    let mut escaped = String::new();
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04X}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    format!("\"{escaped}\"")
+}
+
+/// Strip the rust type suffix of a number literal, ex: `1u64`, which is not valid in toml
+fn strip_number_suffix(value: &str) -> Option<String> {
+    let (sign, number) = if let Some(number) = value.strip_prefix('-') {
+        ("-", number.trim())
+    } else if let Some(number) = value.strip_prefix('+') {
+        ("+", number.trim())
+    } else {
+        ("", value)
+    };
+    match syn::parse_str::<syn::Lit>(number) {
+        Ok(syn::Lit::Int(i)) if !i.suffix().is_empty() => {
+            Some(format!("{sign}{}", i.base10_digits()))
+        }
+        // a float needs a digit after the dot in toml, ex: `2.` is `2.0`
+        Ok(syn::Lit::Float(f)) if f.base10_digits().ends_with('.') => {
+            Some(format!("{sign}{}0", f.base10_digits()))
+        }
+        Ok(syn::Lit::Float(f)) if !f.suffix().is_empty() || !sign.is_empty() => {
+            Some(format!("{sign}{}", f.base10_digits()))
+        }
+        _ => None,
+    }
+}
+
+/// Find the first `pat` which is not enclosed in brackets, braces, parentheses, a string or a char
+fn find_unenclosed_char(pat: char, s: &str) -> Option<usize> {
+    let mut depth = 0;
+    let mut quote = None;
+    let mut escaped = false;
+    for (i, c) in s.char_indices() {
+        if let Some(q) = quote {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                c if c == q => quote = None,
+                _ => (),
+            }
+        } else if c == pat && depth == 0 {
+            return Some(i);
+        } else {
+            match c {
+                '"' | '\'' => quote = Some(c),
+                '[' | '{' | '(' => depth += 1,
+                ']' | '}' | ')' => depth -= 1,
+                _ => (),
+            }
+        }
+    }
+    None
+}
+
+/// Split the attribute tokens by `pat`, empty items from trailing separators are dropped
+fn split_unenclosed(mut s: &str, pat: char) -> Vec<&str> {
+    let mut items = Vec::new();
+    while let Some(i) = find_unenclosed_char(pat, s) {
+        items.push(s[..i].trim());
+        s = &s[i + pat.len_utf8()..];
+    }
+    items.push(s.trim());
+    items.retain(|i| !i.is_empty());
+    items
+}
+
+/// The comment of a constraint of `#[validate(...)]`, ex: `range(min = 1, max = 10)` is
+/// `must be between 1 and 10`
+#[cfg(feature = "validator")]
+fn validate_constraint(item: &str) -> Option<String> {
+    let (name, args) = item.split_once('(').unwrap_or((item, ""));
+    let mut bounds = std::collections::HashMap::new();
+    for arg in split_unenclosed(args.trim().trim_end_matches(')'), ',') {
+        if let (k, Some(v)) = split_key_value(arg) {
+            bounds.insert(k, v.trim_matches('"'));
+        }
+    }
+    let bound = |min: &str, max: &str| match (bounds.get(min), bounds.get(max)) {
+        (Some(min), Some(max)) => Some(format!("between {min} and {max}")),
+        (Some(min), None) => Some(format!("at least {min}")),
+        (None, Some(max)) => Some(format!("at most {max}")),
+        (None, None) => None,
+    };
+    match name.trim() {
+        "range" => bound("min", "max")
+            .or_else(|| match (bounds.get("exclusive_min"), bounds.get("exclusive_max")) {
+                (Some(min), Some(max)) => Some(format!("greater than {min} and less than {max}")),
+                (Some(min), None) => Some(format!("greater than {min}")),
+                (None, Some(max)) => Some(format!("less than {max}")),
+                (None, None) => None,
+            })
+            .map(|b| format!("must be {b}")),
+        "length" => bounds
+            .get("equal")
+            .map(|e| format!("length must be {e}"))
+            .or_else(|| bound("min", "max").map(|b| format!("length must be {b}"))),
+        "email" => Some("must be an email".into()),
+        "url" => Some("must be a url".into()),
+        _ => None,
+    }
+}
+
+/// Parse the string literal in the attribute value
+fn parse_lit_str(attr: &Attribute, s: &str) -> String {
+    if let Ok(lit_str) = syn::parse_str::<LitStr>(s) {
+        lit_str.value()
+    } else {
+        abort!(attr, format!("{s} should be a string literal"))
+    }
+}
+
+/// Split `key = value` of an attribute item, the value is `None` for a bare key
+fn split_key_value(item: &str) -> (&str, Option<&str>) {
+    if let Some((k, v)) = item.split_once('=') {
+        (k.trim(), Some(v.trim()))
+    } else {
+        (item.trim(), None)
+    }
 }
 
 fn push_doc_string(example: &mut String, docs: Vec<String>) {
     for doc in docs.into_iter() {
         example.push('#');
-        example.push_str(&doc);
+        if let Some(doc) = doc.strip_prefix('#') {
+            // a doc already starting with `#`, ex: `#[doc = "# heading"]`, is not commented twice
+            example.push(' ');
+            example.push_str(doc.trim_start_matches('#').trim_start());
+        } else {
+            example.push_str(&doc);
+        }
         example.push('\n');
     }
 }
 
-fn default_key(default: DefaultSource) -> String {
+/// The key of a map from the default, which is quoted if it is not a bare key, ex: `"http.01"`, and
+/// the dots are replaced by dashes with `dash_key`, ex: `http-01`
+fn default_key(default: DefaultSource, dash_key: bool) -> String {
     if let DefaultSource::DefaultValue(v) = default {
-        let key = v.trim_matches('\"').replace(' ', "").replace('.', "-");
-        if !key.is_empty() {
+        // a string literal is the key as it is, the spaces of the tokens are not a part of the key
+        let key = if let Ok(lit_str) = syn::parse_str::<LitStr>(&v) {
+            lit_str.value()
+        } else {
+            v.replace(' ', "")
+        };
+        let key = if dash_key { key.replace('.', "-") } else { key };
+        if key
+            .chars()
+            .any(|c| !(c.is_ascii_alphanumeric() || c == '_' || c == '-'))
+        {
+            // only the bare key can be unquoted
+            return basic_string(&key);
+        } else if !key.is_empty() {
             return key;
         }
     }
@@ -312,166 +1389,1011 @@ pub fn derive(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
 impl Intermediate{
     pub fn from_ast(
         DeriveInput {
-            ident, data, attrs, ..
+            ident,
+            data,
+            attrs,
+            mut generics,
+            ..
         }: syn::DeriveInput,
     ) -> Result<Intermediate> {
         let struct_name = ident.clone();
 
-        let FieldMeta{ docs, rename_rule, .. } = parse_attrs(&attrs);
+        // The type params of nesting and flatten fields are bounded with TomlExample, besides the
+        // existing bounds
+        let fields: Vec<&Field> = match &data {
+            syn::Data::Struct(syn::DataStruct { fields, .. }) => fields.iter().collect(),
+            syn::Data::Enum(syn::DataEnum { variants, .. }) => {
+                variants.iter().flat_map(|v| v.fields.iter()).collect()
+            }
+            syn::Data::Union(_) => Vec::new(),
+        };
+        let bounded_params: Vec<Ident> = generics
+            .type_params()
+            .map(|p| p.ident.clone())
+            .filter(|p| {
+                fields.iter().any(|f| {
+                    let meta = parse_attrs(&f.attrs);
+                    !meta.skip
+                        && (meta.flatten || meta.is_enum || meta.nesting_format.is_some())
+                        && uses_type_param(f.ty.to_token_stream(), p)
+                })
+            })
+            .collect();
+        for param in bounded_params {
+            generics
+                .make_where_clause()
+                .predicates
+                .push(syn::parse_quote!(#param: toml_example::TomlExample));
+        }
+
+        let struct_meta = parse_attrs(&attrs);
 
         let struct_doc = {
             let mut doc = String::new();
-            push_doc_string(&mut doc, docs);
+            push_doc_string(&mut doc, struct_meta.docs.clone());
             doc
         };
 
-        let fields = if let syn::Data::Struct(syn::DataStruct { fields, .. }) = &data {
-            fields
-        } else {
-            abort!(ident, "TomlExample derive only use for struct")
+        // with `#[toml_example(default)]` on a struct, the fields without default are rendered from
+        // the default instance, which is given by `#[serde(default = "path")]` or `Default` of the struct
+        let default_instance = match &struct_meta.default_source {
+            Some(DefaultSource::SerdeDefaultFn(f)) => format!("{f}()"),
+            _ => "<Self as Default>::default()".to_string(),
         };
+        let default_instance = Some(default_instance).filter(|_| {
+            struct_meta.inherit_default
+                && matches!(data, syn::Data::Struct(syn::DataStruct { fields: Named(_), .. }))
+        });
 
-        let field_example = Self::parse_field_examples(fields, rename_rule);
+        // the fields are parsed once, such that the warnings of a field are emitted once
+        let parsed_fields: Vec<ParsedField> = match &data {
+            syn::Data::Struct(syn::DataStruct {
+                fields: fields @ Named(_),
+                ..
+            }) => fields.iter().map(parse_field).collect(),
+            _ => Vec::new(),
+        };
+        let keys = match &data {
+            syn::Data::Struct(syn::DataStruct {
+                fields: fields @ Named(_),
+                ..
+            }) => Some(Self::parse_field_keys(fields, &parsed_fields, &struct_meta)),
+            _ => None,
+        };
+        if let syn::Data::Struct(syn::DataStruct {
+            fields: fields @ Named(_),
+            ..
+        }) = &data
+        {
+            for (f, p) in fields.iter().zip(&parsed_fields) {
+                let recursive = type_path(&f.ty).is_some_and(|ty| ty == "Self" || struct_name == ty);
+                if p.skip || !recursive {
+                    continue;
+                }
+                if p.flatten {
+                    abort!(&f.ident, "a recursive field can not be flattened, please use `nesting`")
+                }
+                match p.nesting_format {
+                    Some(NestingFormat::Inline(_)) => abort!(
+                        &f.ident,
+                        "a recursive field can not be inline, please use `nesting` or `nesting = prefix`"
+                    ),
+                    // the recursive section is not rendered, so the field is missing in the example
+                    Some(_) if !p.optional && !p.serde_default && !struct_meta.serde_default => abort!(
+                        &f.ident,
+                        "a recursive field is missing in the example, please add `#[serde(default)]`"
+                    ),
+                    _ => (),
+                }
+            }
+        }
+        // the sections of a generic type are not known until the type is given, so it is not checked
+        let inline_types = match &data {
+            syn::Data::Struct(syn::DataStruct {
+                fields: fields @ Named(_),
+                ..
+            }) => fields
+                .iter()
+                .zip(&parsed_fields)
+                .filter(|(f, p)| {
+                    !p.skip
+                        && matches!(p.nesting_format, Some(NestingFormat::Inline(_)))
+                        && !generics
+                            .type_params()
+                            .any(|g| uses_type_param(f.ty.to_token_stream(), &g.ident))
+                })
+                .filter_map(|(f, _)| parse_type(&f.ty, &mut String::new(), &mut false, &mut None).and(type_path(&f.ty)))
+                .filter(|ty| ty != "Self")
+                .collect(),
+            _ => Vec::new(),
+        };
+        let has_sections = match &data {
+            syn::Data::Struct(syn::DataStruct {
+                fields: fields @ Named(_),
+                ..
+            }) => Self::parse_has_sections(fields, &parsed_fields),
+            syn::Data::Enum(_) if struct_meta.enum_variants_as_sections => "true".to_string(),
+            _ => "false".to_string(),
+        };
+
+        let mut inline_example = None;
+        let field_example = match &data {
+            syn::Data::Struct(syn::DataStruct {
+                fields: Fields::Unnamed(fields),
+                ..
+            }) => {
+                // serde serializes a tuple struct as an array, which can only be inline, so the
+                // example document only has the value in a comment
+                let example = Self::parse_tuple_example(fields, &struct_meta);
+                inline_example = Some(example.clone());
+                format!("label.to_string() + &toml_example::__private::comment_out(&({example} + \"\\n\"))")
+            }
+            syn::Data::Struct(syn::DataStruct { fields, .. }) => format!(
+                "label.to_string() + &{}",
+                Self::parse_field_examples(
+                    fields,
+                    parsed_fields,
+                    &struct_meta,
+                    default_instance.is_some(),
+                )
+            ),
+            syn::Data::Enum(syn::DataEnum { variants, .. })
+                if struct_meta.enum_variants_as_sections =>
+            {
+                Self::parse_variant_examples(variants.iter(), &struct_meta)
+            }
+            syn::Data::Enum(syn::DataEnum { variants, .. })
+                if struct_meta.tag.is_some() && struct_meta.content.is_some() =>
+            {
+                format!(
+                    "label.to_string() + &{}",
+                    Self::parse_adjacently_tagged_example(variants.iter(), &struct_meta)
+                )
+            }
+            syn::Data::Enum(syn::DataEnum { variants, .. })
+                if variants.iter().all(|v| matches!(v.fields, Fields::Unit)) =>
+            {
+                // serde serializes a unit variant as a string, which can only be inline, so the
+                // example document only has the value in a comment
+                let example = Self::parse_unit_variant_example(variants.iter(), &struct_meta);
+                inline_example = Some(example.clone());
+                format!("label.to_string() + &toml_example::__private::comment_out(&({example} + \"\\n\"))")
+            }
+            _ => abort!(
+                ident,
+                "TomlExample derive only use for struct, enum with unit variants, adjacently tagged enum, or enum with #[toml_example(enum_variants_as_sections)]"
+            ),
+        };
 
         Ok(Intermediate {
             struct_name,
+            generics,
             struct_doc,
             field_example,
+            inline_example,
+            keys,
+            default_instance,
+            version: struct_meta.version,
+            has_sections,
+            inline_types,
         })
     }
     pub fn to_token_stream(&self) -> Result<TokenStream> {
         let Intermediate {
             struct_name,
+            generics,
             struct_doc,
             field_example,
+            inline_example,
+            keys,
+            default_instance,
+            version,
+            has_sections,
+            inline_types,
         } = self;
 
         let field_example_stream: proc_macro2::TokenStream = field_example.parse()?;
+        let has_sections_stream = if has_sections == "false" {
+            quote! {}
+        } else {
+            let has_sections: proc_macro2::TokenStream = has_sections.parse()?;
+            quote! {
+                const HAS_SECTIONS: bool = #has_sections;
+            }
+        };
+        let inline_checks = inline_types
+            .iter()
+            .map(|ty| {
+                let ty: proc_macro2::TokenStream = ty.parse()?;
+                Ok(quote! {
+                    const _: () = toml_example::__private::assert_inline::<
+                        toml_example::__private::HasSections<{ <#ty as toml_example::TomlExample>::HAS_SECTIONS }>,
+                    >();
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let inline_example_stream = if let Some(inline_example) = inline_example {
+            let inline_example_stream: proc_macro2::TokenStream = inline_example.parse()?;
+            quote! {
+                fn toml_example_inline() -> String {
+                    #inline_example_stream
+                }
+            }
+        } else {
+            quote! {}
+        };
+        // the sections of a struct already in the parents are dropped, and the struct is the parent
+        // of its sections
+        let parents_stream = quote! {
+            #[allow(unused_variables)]
+            let recursive = parents.contains(&core::any::type_name::<Self>());
+            #[allow(unused_variables)]
+            let parents = [parents, &[core::any::type_name::<Self>()]].concat();
+        };
+        let has_default_instance = default_instance.is_some();
+        let example_stream = if let Some(default_instance) = default_instance {
+            let default_instance_stream: proc_macro2::TokenStream = default_instance.parse()?;
+            quote! {
+                fn toml_example_with_prefix(label: &str, prefix: &str) -> String {
+                    Self::toml_example_in_section(label, prefix, &[])
+                }
+                fn toml_example_in_section(label: &str, prefix: &str, parents: &[&str]) -> String {
+                    #parents_stream
+                    #[allow(unused_variables)]
+                    let default = &#default_instance_stream;
+                    #struct_doc.to_string() + &#field_example_stream
+                }
+                fn toml_example_with_default(label: &str, prefix: &str, default: &Self) -> String {
+                    let parents: &[&str] = &[];
+                    #parents_stream
+                    #struct_doc.to_string() + &#field_example_stream
+                }
+            }
+        } else {
+            quote! {
+                fn toml_example_with_prefix(label: &str, prefix: &str) -> String {
+                    Self::toml_example_in_section(label, prefix, &[])
+                }
+                fn toml_example_in_section(label: &str, prefix: &str, parents: &[&str]) -> String {
+                    #parents_stream
+                    #struct_doc.to_string() + &#field_example_stream
+                }
+            }
+        };
+        let keys_stream = if let Some(keys) = keys {
+            let keys_stream: proc_macro2::TokenStream = keys.parse()?;
+            quote! {
+                fn toml_example_keys() -> Vec<String> {
+                    #keys_stream
+                }
+            }
+        } else {
+            quote! {}
+        };
+        // the version is only on the top of the example, not in the section of a nesting struct
+        let version_stream = if let Some(version) = version {
+            let version_comment = format!("# schema version: {version}\n\n");
+            quote! { #version_comment.to_string() + & }
+        } else {
+            quote! {}
+        };
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        // the struct with a default instance can be rendered from the default of the parent
+        let with_default_stream = if has_default_instance {
+            quote! {
+                impl #impl_generics toml_example::__private::WithDefault for #struct_name #ty_generics #where_clause {}
+            }
+        } else {
+            quote! {}
+        };
 
+        // the example is generated with alloc, such that it also works in a no_std crate
         Ok(quote! {
-            impl toml_example::TomlExample for #struct_name {
-                fn toml_example() -> String {
-                    #struct_name::toml_example_with_prefix("", "")
+            const _: () = {
+                extern crate alloc;
+                #[allow(unused_imports)]
+                use alloc::{format, string::{String, ToString}, vec::Vec};
+                #(#inline_checks)*
+
+                impl #impl_generics toml_example::TomlExample for #struct_name #ty_generics #where_clause {
+                    fn toml_example() -> String {
+                        #version_stream Self::toml_example_with_prefix("", "")
+                    }
+                    #has_sections_stream
+                    #example_stream
+                    #inline_example_stream
+                    #keys_stream
                 }
-                fn toml_example_with_prefix(label: &str, prefix: &str) -> String{
-                    #struct_doc.to_string() + label + &#field_example_stream
+                #with_default_stream
+            };
+        })
+    }
+
+    /// Whether the example has a section header, a flattened struct has the sections of its own
+    fn parse_has_sections(fields: &Fields, parsed_fields: &[ParsedField]) -> String {
+        let mut has_sections = vec!["false".to_string()];
+        for (f, parsed) in fields.iter().zip(parsed_fields) {
+            if parsed.skip {
+                continue;
+            }
+            if matches!(parsed.nesting_format, Some(NestingFormat::Section(_))) {
+                return "true".to_string();
+            }
+            if parsed.flatten {
+                // the catch-all `Value` has no keys, the last segment is the name, ex: `toml::Value`
+                let type_name = parse_type(&f.ty, &mut String::new(), &mut false, &mut None);
+                match type_name.filter(|t| t != "Value").and(type_path(&f.ty)) {
+                    Some(field_type) if map_value_default(&f.ty).is_none() => {
+                        has_sections.push(format!("<{field_type} as toml_example::TomlExample>::HAS_SECTIONS"))
+                    }
+                    _ => (),
                 }
             }
-        })
+        }
+        has_sections.join(" || ")
+    }
+
+    /// The top-level keys of the fields, the keys of a flattened struct are included
+    fn parse_field_keys(fields: &Fields, parsed_fields: &[ParsedField], struct_meta: &FieldMeta) -> String {
+        let mut keys = "{ let mut keys: Vec<String> = Vec::new();".to_string();
+        for (f, parsed) in fields.iter().zip(parsed_fields) {
+            let Some(ident) = &f.ident else {
+                continue;
+            };
+            let ParsedField {
+                section_name,
+                nesting_format,
+                flatten,
+                skip,
+                rename,
+                ..
+            } = parsed;
+            if *skip {
+                continue;
+            }
+            if *flatten {
+                // the catch-all `Value` has no keys, the last segment is the name, ex: `toml::Value`
+                let type_name = parse_type(&f.ty, &mut String::new(), &mut false, &mut None);
+                match type_name.filter(|t| t != "Value").and(type_path(&f.ty)) {
+                    Some(field_type) if map_value_default(&f.ty).is_none() => {
+                        keys.push_str(&format!(" keys.extend({field_type}::toml_example_keys());"))
+                    }
+                    _ => (),
+                }
+                continue;
+            }
+            let mut key = rename.clone().unwrap_or_else(|| {
+                struct_meta
+                    .rename_rule
+                    .apply_to_field(ident.to_string().trim_start_matches("r#"))
+            });
+            if matches!(nesting_format, Some(NestingFormat::Section(_))) {
+                key = section_name.clone().unwrap_or(key);
+            }
+            keys.push_str(&format!(" keys.push({key:?}.to_string());"));
+        }
+        keys.push_str(" keys }");
+        keys
     }
 
-    fn parse_field_examples(fields: &Fields, rename_rule: case::RenameRule) -> String {
+    /// The variant with `#[toml_example(default)]`, or the first variant is rendered as
+    /// `tag = "Variant"` and `content = { ... }`
+    fn parse_adjacently_tagged_example<'a>(
+        mut variants: impl Iterator<Item = &'a Variant> + Clone,
+        enum_meta: &FieldMeta,
+    ) -> String {
+        let Some(v) = variants
+            .clone()
+            .find(|v| parse_attrs(&v.attrs).default_source.is_some())
+            .or_else(|| variants.next())
+        else {
+            return "String::new()".to_string();
+        };
+        let variant_meta = parse_attrs(&v.attrs);
+        let variant_name = if let Some(rename) = &variant_meta.rename {
+            rename.clone()
+        } else {
+            enum_meta.rename_rule.apply_to_variant(&v.ident.to_string())
+        };
+        let tag = enum_meta.tag.as_deref().unwrap_or_default();
+        let content = enum_meta.content.as_deref().unwrap_or_default();
+        let kv_separator = &enum_meta.kv_separator;
+        let mut example = format!(
+            "r##\"\"##.to_string() + prefix + r##\"{tag}{kv_separator}\"{variant_name}\"\n\"##"
+        );
+        match &v.fields {
+            Named(_) => {
+                let parsed_fields: Vec<ParsedField> = v.fields.iter().map(parse_field).collect();
+                // the content is an inline table, which can not have a section
+                if let Some(f) = v.fields.iter().zip(&parsed_fields).find_map(|(f, p)| {
+                    (!p.skip && matches!(p.nesting_format, Some(NestingFormat::Section(_)))).then_some(f)
+                }) {
+                    abort!(&f.ident, "the content of an adjacently tagged enum is an inline table, please use `nesting = inline`")
+                }
+                example.push_str(&format!(
+                    " + prefix + r##\"{content}{kv_separator}\"## + &toml_example::__private::inline_table(&{{ let prefix = \"\"; {} }}) + \"\\n\"",
+                    Self::parse_field_examples(&v.fields, parsed_fields, &variant_meta, false)
+                ))
+            }
+            Fields::Unnamed(fields) => example.push_str(&format!(
+                " + prefix + r##\"{content}{kv_separator}\"## + &{} + \"\\n\"",
+                Self::parse_tuple_example(fields, enum_meta)
+            )),
+            Fields::Unit => (),
+        }
+        example.push_str(" + \"\\n\"");
+        example
+    }
+
+    /// The default variant, `#[default]` or `#[toml_example(default)]`, or the first variant is
+    /// rendered as a string, or as the discriminant with `#[toml_example(enum_as_int)]`
+    fn parse_unit_variant_example<'a>(
+        mut variants: impl Iterator<Item = &'a Variant> + Clone,
+        enum_meta: &FieldMeta,
+    ) -> String {
+        let Some(v) = variants
+            .clone()
+            .find(|v| {
+                v.attrs.iter().any(|a| a.path().is_ident("default"))
+                    || parse_attrs(&v.attrs).default_source.is_some()
+            })
+            .or_else(|| variants.next())
+        else {
+            return "r##\"\"\"\"##.to_string()".to_string();
+        };
+        if enum_meta.enum_as_int {
+            return format!("format!(\"{{}}\", Self::{} as i64)", v.ident);
+        }
+        let variant_name = if let Some(rename) = parse_attrs(&v.attrs).rename {
+            rename
+        } else {
+            enum_meta.rename_rule.apply_to_variant(&v.ident.to_string())
+        };
+        format!("r##\"\"{variant_name}\"\"##.to_string()")
+    }
+
+    /// The example of a tuple struct is an array, and the example of a newtype struct is the
+    /// value of the inner type.
+    fn parse_tuple_example(fields: &FieldsUnnamed, struct_meta: &FieldMeta) -> String {
+        let items: Vec<String> = fields
+            .unnamed
+            .iter()
+            .map(|f| match parse_field(f).default {
+                DefaultSource::DefaultValue(v) => format!(
+                    "r##\"{}\"##.to_string()",
+                    format_value(&v, struct_meta.array_style)
+                ),
+                DefaultSource::DefaultFn(Some(ty)) => {
+                    format!("format!(\"{{:?}}\", <{ty} as Default>::default())")
+                }
+                DefaultSource::DefaultFn(None) => "r##\"\"\"\"##.to_string()".to_string(),
+                DefaultSource::SerdeDefaultFn(fn_str) => format!("format!(\"{{:?}}\", {fn_str}())"),
+                DefaultSource::ConstPath(c) => {
+                    format!("toml_example::__private::format_scalar(&format!(\"{{:?}}\", {c}))")
+                }
+            })
+            .collect();
+        if items.len() == 1 {
+            return items[0].clone();
+        }
+        let template = match struct_meta.array_style {
+            ArrayStyle::Spaced => "[ {}, ]",
+            ArrayStyle::Compact => "[{}]",
+        };
+        format!(
+            "format!(\"{template}\", [{}].join(\", \"))",
+            items.join(", ")
+        )
+    }
+
+    /// Each struct variant is a commented-out section, the sections are placed under the
+    /// section of label if any.
+    fn parse_variant_examples<'a>(
+        variants: impl Iterator<Item = &'a Variant>,
+        enum_meta: &FieldMeta,
+    ) -> String {
+        let mut variant_example = "{ let section = label.lines().last().unwrap_or_default().trim_start_matches('#').trim().trim_matches(|c| c == '[' || c == ']'); let section = if section.is_empty() { String::new() } else { format!(\"{section}.\") }; String::new()".to_string();
+        for v in variants {
+            let variant_meta = parse_attrs(&v.attrs);
+            if variant_meta.skip {
+                continue;
+            }
+            if !matches!(v.fields, Named(_)) {
+                abort!(&v.ident, "enum_variants_as_sections only work on struct variants")
+            }
+            let variant_name = if let Some(rename) = &variant_meta.rename {
+                rename.clone()
+            } else {
+                enum_meta.rename_rule.apply_to_variant(&v.ident.to_string())
+            };
+            let mut doc = String::new();
+            push_doc_string(&mut doc, variant_meta.docs.clone());
+            variant_example.push_str(&format!(
+                " + &toml_example::__private::comment_out(&(r##\"{doc}[\"##.to_string() + &section + r##\"{variant_name}]\n\"## + &{}))",
+                Self::parse_field_examples(
+                    &v.fields,
+                    v.fields.iter().map(parse_field).collect(),
+                    &variant_meta,
+                    false,
+                )
+            ));
+        }
+        variant_example.push_str(" }");
+        variant_example
+    }
+
+    /// With `inherit_default`, the fields without default are rendered from the `default` instance
+    /// The sections are dropped if the struct is already in the `parents` of the section, ex: the
+    /// section of `Option<Box<Self>>`, such that a recursive example is not generated infinitely
+    fn parse_field_examples(
+        fields: &Fields,
+        parsed_fields: Vec<ParsedField>,
+        struct_meta: &FieldMeta,
+        inherit_default: bool,
+    ) -> String {
         // Always put nesting field example in the last to avoid #18
         let mut field_example = "r##\"".to_string();
+        let mut flatten_field_example = "".to_string();
         let mut nesting_field_example = "".to_string();
+        // with `sections_inline`, a key after a section belongs to the section in toml, and the keys
+        // of the flattened fields are placed after all the fields
+        let is_section = |p: &ParsedField| !p.skip && matches!(p.nesting_format, Some(NestingFormat::Section(_)));
+        let has_section = struct_meta.sections_inline && parsed_fields.iter().any(is_section);
+        let mut after_section = false;
 
         if let Named(named_fields) = fields {
-            for f in named_fields.named.iter() {
-                let field_type = parse_type(&f.ty, &mut String::new(), &mut false, &mut None);
-                if let Some(mut field_name) = f.ident.as_ref().map(|i| i.to_string()) {
-                    let (default, doc_str, optional, nesting_format, skip, rename) = parse_field(f);
+            for (f, parsed) in named_fields.named.iter().zip(parsed_fields) {
+                let commented = parsed.optional && !struct_meta.all_required;
+                if has_section
+                    && !parsed.skip
+                    && !commented
+                    && !is_section(&parsed)
+                    && (after_section || parsed.flatten)
+                {
+                    abort!(
+                        &f.ident,
+                        "the key after a section belongs to the section in toml, please place the field before the nesting sections with `sections_inline`"
+                    )
+                }
+                after_section |= has_section && is_section(&parsed);
+                let type_name = parse_type(&f.ty, &mut String::new(), &mut false, &mut None);
+                // the methods of the nesting type are called with the path of it
+                let field_type = type_name.as_ref().and(type_path(&f.ty));
+                if let Some(mut field_name) = f
+                    .ident
+                    .as_ref()
+                    .map(|i| i.to_string().trim_start_matches("r#").to_string())
+                {
+                    let ParsedField {
+                        default,
+                        docs: doc_str,
+                        notes,
+                        title,
+                        section_name,
+                        section_example,
+                        map_key_comment,
+                        optional,
+                        nesting_format,
+                        show_none,
+                        inline_doc,
+                        base64,
+                        duration_secs,
+                        as_string,
+                        width,
+                        unknown_placeholder,
+                        has_default,
+                        empty_template,
+                        commented,
+                        serde_default: _,
+                        dash_key,
+                        flatten,
+                        skip,
+                        rename,
+                    } = parsed;
                     if skip {
                         continue;
                     }
+                    if empty_template && !struct_meta.serde_default {
+                        abort!(
+                            &f.ident,
+                            "the array of empty_template is missing in the example, please add `#[serde(default)]`"
+                        )
+                    }
+                    if commented && !struct_meta.serde_default {
+                        abort!(
+                            &f.ident,
+                            "the commented field is missing in the example, please add `#[serde(default)]`"
+                        )
+                    }
+                    // all optional fields are required with `#[toml_example(all_required)]`
+                    let optional = optional && !struct_meta.all_required;
+                    // the value of the field in the default instance, ex: `default.inner`
+                    let inherited = (inherit_default && !has_default && !optional)
+                        .then(|| format!("default.{}", f.ident.as_ref().unwrap()));
                     if let Some(rename) = rename {
                         field_name = rename;
                     } else {
-                        field_name = rename_rule.apply_to_field(&field_name);
+                        field_name = struct_meta.rename_rule.apply_to_field(&field_name);
                     }
-                    if nesting_format
+                    if flatten {
+                        // a warning of the derive is not shown on stable, so it is a comment in the example
+                        if struct_meta.deny_unknown_fields {
+                            flatten_field_example.push_str(
+                                "# WARNING: serde does not support `deny_unknown_fields` with `flatten`, the following keys are flattened\n",
+                            );
+                        }
+                        // The keys of flattened struct are placed after the keys of this struct,
+                        // and before the nesting sections
+                        if let Some(value) = map_value_default(&f.ty) {
+                            // catch-all map, an arbitrary key is shown as a commented entry
+                            let value = if type_name.as_deref() == Some("Value") {
+                                "\"value\"".to_string()
+                            } else {
+                                format_value(&value, struct_meta.array_style)
+                            };
+                            push_doc_string(&mut flatten_field_example, doc_str);
+                            flatten_field_example.push_str("# additional keys allowed here\n");
+                            flatten_field_example.push_str(&format!("# extra_key{}{value}\n\n", struct_meta.kv_separator));
+                        } else if type_name.as_deref() == Some("Value") {
+                            // catch-all of toml::Value, serde_json::Value, the keys are unknown
+                            push_doc_string(&mut flatten_field_example, doc_str);
+                            flatten_field_example.push_str("# additional keys allowed here\n\n");
+                        } else if let Some(field_type) = field_type {
+                            push_doc_string(&mut flatten_field_example, doc_str);
+                            flatten_field_example.push_str("\"##.to_string()");
+                            if optional {
+                                // the whole group of keys is optional, so all of them are commented out
+                                flatten_field_example.push_str(&format!(
+                                    " + &toml_example::__private::comment_out(&{field_type}::toml_example_with_prefix(\"\", prefix))"
+                                ));
+                            } else if let Some(inherited) = &inherited {
+                                flatten_field_example.push_str(&format!(
+                                    " + &toml_example::__private::with_default(\"\", prefix, &{inherited})"
+                                ));
+                            } else {
+                                flatten_field_example.push_str(&format!(
+                                    " + &{field_type}::toml_example_with_prefix(\"\", prefix)"
+                                ));
+                            }
+                            flatten_field_example.push_str(" + &r##\"");
+                        } else {
+                            abort!(&f.ident, "flatten only work on inner structure")
+                        }
+                    } else if nesting_format
                         .as_ref()
                         .map(|f| matches!(f, NestingFormat::Section(_)))
                         .unwrap_or_default()
                     {
                         if let Some(field_type) = field_type {
-                            push_doc_string(&mut nesting_field_example, doc_str);
-                            nesting_field_example.push_str("\"##.to_string()");
-                            let key = default_key(default);
-                            match nesting_format {
-                                Some(NestingFormat::Section(NestingType::Vec)) if optional => nesting_field_example.push_str(&format!(
-                                    " + &{field_type}::toml_example_with_prefix(\"# [[{field_name:}]]\n\", \"# \")"
-                                )),
-                                Some(NestingFormat::Section(NestingType::Vec)) => nesting_field_example.push_str(&format!(
-                                    " + &{field_type}::toml_example_with_prefix(\"[[{field_name:}]]\n\", \"\")"
-                                )),
-                                Some(NestingFormat::Section(NestingType::Dict)) if optional => nesting_field_example.push_str(&format!(
-                                    " + &{field_type}::toml_example_with_prefix(\"# [{field_name:}.{key}]\n\", \"# \")"
-                                )),
-                                Some(NestingFormat::Section(NestingType::Dict)) => nesting_field_example.push_str(&format!(
-                                    " + &{field_type}::toml_example_with_prefix(\"[{field_name:}.{key}]\n\", \"\")"
-                                )),
-                                _ if optional => nesting_field_example.push_str(&format!(
-                                    " + &{field_type}::toml_example_with_prefix(\"# [{field_name:}]\n\", \"# \")"
-                                )),
-                                _ => nesting_field_example.push_str(&format!(
-                                    " + &{field_type}::toml_example_with_prefix(\"[{field_name:}]\n\", \"\")"
-                                ))
+                            // the sections are at the bottom to avoid #18, unless `sections_inline` is set
+                            let section_field_example = if struct_meta.sections_inline {
+                                &mut field_example
+                            } else {
+                                &mut nesting_field_example
+                            };
+                            let mut doc = String::new();
+                            push_doc_string(&mut doc, doc_str);
+                            if struct_meta.show_rust_type {
+                                doc.push_str(&format!("# rust type: {}\n", rust_type_name(&f.ty)));
+                            }
+                            // the placeholder of the type is not a key
+                            let key = default_key(if has_default { default } else { DefaultSource::DefaultFn(None) }, dash_key);
+                            let title = title.map(|t| format!("# --- {t} ---\n")).unwrap_or_default();
+                            // the section name overrides the key of the field
+                            let field_name = section_name.unwrap_or(field_name);
+                            // the notes are placed below the section header
+                            let notes: String = notes.iter().map(|n| format!("# {n}\n")).collect();
+                            let comment = if optional { "# " } else { "" };
+                            let header = match nesting_format {
+                                Some(NestingFormat::Section(NestingType::Vec)) => format!("{comment}[[{field_name}]]\n"),
+                                Some(NestingFormat::Section(NestingType::Dict)) => format!("{comment}[{field_name}.{key}]\n"),
+                                Some(NestingFormat::Section(NestingType::VecDict)) => {
+                                    format!("{comment}[[{field_name}]]\n{comment}[{field_name}.{key}]\n")
+                                }
+                                Some(NestingFormat::Section(NestingType::DictVec)) => {
+                                    format!("{comment}[[{field_name}.{key}]]\n")
+                                }
+                                _ => format!("{comment}[{field_name}]\n"),
                             };
-                            nesting_field_example.push_str(" + &r##\"");
+                            // the comment explaining the placeholder key is above the header
+                            let map_key_comment = map_key_comment.map(|c| format!("# {c}\n")).unwrap_or_default();
+                            let label = format!("{title}{map_key_comment}{header}{notes}");
+                            let section_example = if let Some(body) = section_example {
+                                // the curated body is placed under the section header as it is, and
+                                // the doc of the inner struct is kept above the header
+                                let body: String = body
+                                    .split_inclusive('\n')
+                                    .map(|l| if l.trim().is_empty() { l.to_string() } else { format!("{comment}{l}") })
+                                    .collect();
+                                format!(
+                                    "{{ let s = {field_type}::toml_example_in_section({label:?}, {comment:?}, &parents); \
+                                    s.split_once({label:?}).map(|(doc, _)| doc).unwrap_or_default().to_string() + {:?} }}",
+                                    label.clone() + &body
+                                )
+                            } else if let Some(inherited) = inherited.as_ref().filter(|_| {
+                                !optional && matches!(nesting_format, Some(NestingFormat::Section(NestingType::None)))
+                            }) {
+                                format!("toml_example::__private::with_default({label:?}, \"\", &{inherited})")
+                            } else {
+                                format!("{field_type}::toml_example_in_section({label:?}, {comment:?}, &parents)")
+                            };
+                            let section_example = if struct_meta.indent_tabs {
+                                format!("toml_example::__private::indent_body(&{section_example}, {label:?})")
+                            } else {
+                                section_example
+                            };
+                            // a section ends with exactly one blank line, even if the inner structure
+                            // has no key, so adjacent sections do not run together or double the blank line,
+                            // and the section is dropped in a recursive example
+                            section_field_example.push_str(&format!(
+                                "\"##.to_string() + &(if recursive {{ String::new() }} else {{ {doc:?}.to_string() + \
+                                &{{ let s = {section_example}; s.trim_end_matches('\\n').to_string() + \"\\n\\n\" }} }})"
+                            ));
+                            section_field_example.push_str(" + &r##\"");
                         } else {
                             abort!(&f.ident, "nesting only work on inner structure")
                         }
                     } else if nesting_format == Some(NestingFormat::Prefix) {
-                        push_doc_string(&mut field_example, doc_str);
                         if let Some(field_type) = field_type {
+                            let mut doc = String::new();
+                            push_doc_string(&mut doc, doc_str);
                             field_example.push_str("\"##.to_string()");
+                            // the keys of a struct already in the parents are dropped with the doc, as a recursive section
                             if optional {
                                 field_example.push_str(&format!(
-                                    " + &{field_type}::toml_example_with_prefix(\"\", \"# {field_name:}.\")"
+                                    " + &(if recursive {{ String::new() }} else {{ {doc:?}.to_string() + &{field_type}::toml_example_in_section(\"\", \"# {field_name:}.\", &parents) }})"
+                                ));
+                            } else if let Some(inherited) = &inherited {
+                                field_example.push_str(&format!(
+                                    " + {doc:?} + &toml_example::__private::with_default(\"\", \"{field_name:}.\", &{inherited})"
                                 ));
                             } else {
                                 field_example.push_str(&format!(
-                                    " + &{field_type}::toml_example_with_prefix(\"\", \"{field_name:}.\")"
+                                    " + &(if recursive {{ String::new() }} else {{ {doc:?}.to_string() + &{field_type}::toml_example_in_section(\"\", \"{field_name:}.\", &parents) }})"
                                 ));
                             }
                             field_example.push_str(" + &r##\"");
                         } else {
                             abort!(&f.ident, "nesting only work on inner structure")
                         }
-                    } else {
+                    } else if let Some(NestingFormat::Inline(nesting_type)) = nesting_format {
                         push_doc_string(&mut field_example, doc_str);
+                        if let Some(field_type) = field_type {
+                            if optional {
+                                field_example.push_str("# ");
+                            }
+                            let key = default_key(if has_default { default } else { DefaultSource::DefaultFn(None) }, dash_key);
+                            field_example.push_str("\"##.to_string() + prefix + &r##\"");
+                            field_example.push_str(&field_name);
+                            field_example.push_str(&struct_meta.kv_separator);
+                            let inline = format!(
+                                "\"##.to_string() + &toml_example::__private::inline::<{field_type}>() + &r##\""
+                            );
+                            match nesting_type {
+                                NestingType::Vec => field_example.push_str(&format!("[ {inline}, ]")),
+                                NestingType::Dict => {
+                                    field_example.push_str(&format!("{{ {key} = {inline} }}"))
+                                }
+                                NestingType::DictVec => {
+                                    field_example.push_str(&format!("{{ {key} = [ {inline}, ] }}"))
+                                }
+                                NestingType::VecDict => {
+                                    field_example.push_str(&format!("[ {{ {key} = {inline} }}, ]"))
+                                }
+                                NestingType::None => field_example.push_str(&inline),
+                            }
+                            field_example.push('\n');
+                            for note in notes {
+                                field_example.push_str("# ");
+                                field_example.push_str(&note);
+                                field_example.push('\n');
+                            }
+                            field_example.push('\n');
+                        } else {
+                            abort!(&f.ident, "nesting only work on inner structure")
+                        }
+                    } else {
+                        // the value of an unknown type, ex: an enum, can not be rendered from `Debug`
+                        if inherited.is_some()
+                            && !as_string
+                            && !duration_secs
+                            && !type_name.as_deref().is_some_and(is_known_type)
+                        {
+                            abort!(
+                                &f.ident,
+                                "the value of the type can not be rendered from the default of the struct, please provide `#[toml_example(default = ...)]`, or use `enum` or nesting"
+                            )
+                        }
+                        // the inherited value is not a placeholder
+                        let unknown_placeholder = unknown_placeholder && inherited.is_none();
+                        if struct_meta.strict && unknown_placeholder {
+                            abort!(
+                                &f.ident,
+                                "the placeholder of the type is unknown in strict mode, please provide `#[toml_example(default = ...)]`, or use nesting"
+                            )
+                        }
+                        let inline_doc = if inline_doc {
+                            doc_str.first().map(|d| format!(" #{}", d.trim_end()))
+                        } else {
+                            push_doc_string(&mut field_example, doc_str);
+                            None
+                        };
+                        if struct_meta.show_rust_type {
+                            field_example.push_str(&format!("# rust type: {}\n", rust_type_name(&f.ty)));
+                        }
+                        if struct_meta.type_hints {
+                            let hint = if base64 {
+                                Some("string".to_string())
+                            } else if duration_secs {
+                                Some("integer".to_string())
+                            } else if as_string {
+                                Some("string".to_string())
+                            } else {
+                                type_hint(&f.ty)
+                            };
+                            if let Some(hint) = hint {
+                                field_example.push_str(&format!("# ({hint})\n"));
+                            }
+                        }
                         if optional {
                             field_example.push_str("# ");
                         }
+                        // a map from a default function is rendered as an inline table at runtime
+                        let is_map = map_value_default(&f.ty).is_some();
+                        // an array evaluated at runtime is rendered in the array style of the struct
+                        let debug = |expr: &str| {
+                            if is_range(&f.ty) {
+                                format!(" + &toml_example::__private::format_range(&format!(\"{{:?}}\", {expr}))")
+                            } else if is_bytes(&f.ty) {
+                                // the bytes are rendered as a string, instead of the `b"..."` of `Debug`
+                                format!(" + &format!(\"{{:?}}\", String::from_utf8_lossy(&{expr}))")
+                            } else if type_hint(unwrap_option(&f.ty)).is_some_and(|h| h.starts_with("array")) {
+                                format!(
+                                    " + &toml_example::__private::format_array(&format!(\"{{:?}}\", {expr}), {})",
+                                    struct_meta.array_style == ArrayStyle::Compact
+                                )
+                            } else {
+                                // the floats and the strings are rendered in toml, ex: `NaN` is `nan`
+                                format!(" + &toml_example::__private::format_scalar(&format!(\"{{:?}}\", {expr}))")
+                            }
+                        };
                         match default {
+                            _ if inherited.is_some() => {
+                                let inherited = inherited.as_deref().unwrap_or_default();
+                                field_example.push_str("\"##.to_string() + prefix + &r##\"");
+                                field_example.push_str(&field_name);
+                                field_example.push_str(&struct_meta.kv_separator);
+                                field_example.push_str("\"##.to_string()");
+                                if is_map {
+                                    field_example.push_str(&format!(" + &toml_example::__private::inline_map(&{inherited})"));
+                                } else if as_string {
+                                    field_example.push_str(&format!(
+                                        " + &toml_example::__private::format_scalar(&format!(\"{{:?}}\", {inherited}.to_string()))"
+                                    ));
+                                } else if duration_secs {
+                                    field_example.push_str(&format!(" + &format!(\"{{:?}}\", {inherited}.as_secs())"));
+                                } else if is_range(&f.ty) || is_bytes(&f.ty) {
+                                    field_example.push_str(&debug(inherited));
+                                } else {
+                                    field_example.push_str(&format!(
+                                        " + &toml_example::__private::format_array(&format!(\"{{:?}}\", {inherited}), {})",
+                                        struct_meta.array_style == ArrayStyle::Compact
+                                    ));
+                                }
+                                field_example.push_str(" + &r##\"");
+                            }
                             DefaultSource::DefaultValue(default) => {
                                 field_example.push_str("\"##.to_string() + prefix + &r##\"");
-                                field_example.push_str(field_name.trim_start_matches("r#"));
-                                field_example.push_str(" = ");
-                                field_example.push_str(&default);
-                                field_example.push('\n');
+                                field_example.push_str(&field_name);
+                                field_example.push_str(&struct_meta.kv_separator);
+                                let value = format_value(&default, struct_meta.array_style);
+                                // the array wraps if the single line is wider than the width,
+                                // the field width overrides the struct width
+                                let wrapped = width
+                                    .or(struct_meta.width)
+                                    .filter(|w| {
+                                        (optional as usize) * 2
+                                            + field_name.len()
+                                            + struct_meta.kv_separator.len()
+                                            + value.len()
+                                            > *w
+                                    })
+                                    .and_then(|_| wrap_array(&default, struct_meta.array_style, optional));
+                                field_example.push_str(&wrapped.unwrap_or(value));
                             }
                             DefaultSource::DefaultFn(None) => {
                                 field_example.push_str("\"##.to_string() + prefix + &r##\"");
                                 field_example.push_str(&field_name);
-                                field_example.push_str(" = \"\"\n");
+                                field_example.push_str(&struct_meta.kv_separator);
+                                field_example.push_str("\"\"");
                             }
                             DefaultSource::DefaultFn(Some(ty)) => {
                                 field_example.push_str("\"##.to_string() + prefix + &r##\"");
                                 field_example.push_str(&field_name);
-                                field_example.push_str(" = \"##.to_string()");
-                                field_example
-                                    .push_str(&format!(" + &format!(\"{{:?}}\",  {ty}::default())"));
-                                field_example.push_str(" + &r##\"\n");
+                                field_example.push_str(&struct_meta.kv_separator);
+                                field_example.push_str("\"##.to_string()");
+                                if is_map {
+                                    field_example.push_str(&format!(
+                                        " + &toml_example::__private::inline_map(<{ty} as Default>::default())"
+                                    ));
+                                } else if as_string {
+                                    field_example.push_str(&format!(
+                                        " + &format!(\"{{:?}}\",  <{ty} as Default>::default().to_string())"
+                                    ));
+                                } else {
+                                    field_example.push_str(&debug(&format!("<{ty} as Default>::default()")));
+                                }
+                                field_example.push_str(" + &r##\"");
                             }
                             DefaultSource::SerdeDefaultFn(fn_str) => {
                                 field_example.push_str("\"##.to_string() + prefix + &r##\"");
                                 field_example.push_str(&field_name);
-                                field_example.push_str(" = \"##.to_string()");
-                                field_example.push_str(&format!(
-                                    " + &format!(\"{{:?}}\",  {fn_str}())"
-                                ));
-                                field_example.push_str("+ &r##\"\n");
+                                field_example.push_str(&struct_meta.kv_separator);
+                                field_example.push_str("\"##.to_string()");
+                                // the default function returns the type of the field, which may be Option
+                                if is_map && !is_option(&f.ty) {
+                                    field_example.push_str(&format!(
+                                        " + &toml_example::__private::inline_map({fn_str}())"
+                                    ));
+                                } else if duration_secs && !is_option(&f.ty) {
+                                    field_example.push_str(&format!(
+                                        " + &format!(\"{{:?}}\",  {fn_str}().as_secs())"
+                                    ));
+                                } else if as_string && !is_option(&f.ty) {
+                                    field_example.push_str(&format!(
+                                        " + &format!(\"{{:?}}\",  {fn_str}().to_string())"
+                                    ));
+                                } else {
+                                    field_example.push_str(&debug(&format!("{fn_str}()")));
+                                }
+                                field_example.push_str("+ &r##\"");
+                            }
+                            DefaultSource::ConstPath(c) => {
+                                field_example.push_str("\"##.to_string() + prefix + &r##\"");
+                                field_example.push_str(&field_name);
+                                field_example.push_str(&struct_meta.kv_separator);
+                                field_example.push_str("\"##.to_string()");
+                                let c = if duration_secs { format!("{c}.as_secs()") } else { c };
+                                field_example.push_str(&debug(&c));
+                                field_example.push_str(" + &r##\"");
                             }
                         }
+                        if let Some(inline_doc) = inline_doc {
+                            field_example.push_str(&inline_doc);
+                        }
+                        field_example.push('\n');
+                        // the prefix of a commented section, or a commented prefix, is commented already
+                        if show_none {
+                            field_example.push_str(
+                                "\"##.to_string() + if prefix.starts_with('#') { \"\" } else { \"# \" } + prefix + &r##\"",
+                            );
+                            field_example.push_str(&field_name);
+                            field_example.push_str(&struct_meta.kv_separator);
+                            field_example.push_str("<unset>\n");
+                        }
+                        if struct_meta.audit && unknown_placeholder {
+                            field_example.push_str("# WARNING: placeholder may be invalid\n");
+                        }
+                        for note in notes {
+                            field_example.push_str("# ");
+                            field_example.push_str(&note);
+                            field_example.push('\n');
+                        }
                         field_example.push('\n');
                     }
                 }
             }
         }
+        field_example += &flatten_field_example;
+        // the keys are aligned after rendering, such that the prefix and the commented keys are counted
+        if struct_meta.align {
+            field_example = format!(
+                "toml_example::__private::align(&({field_example}\"##.to_string()), {:?}) + &r##\"",
+                struct_meta.kv_separator
+            );
+        }
         field_example += &nesting_field_example;
         field_example.push_str("\"##.to_string()");
-
         field_example
     }
 }
@@ -0,0 +1,208 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+
+/// Customize how the lines of the example are rendered, each method receives a part of a line and
+/// returns the rendered line, the default methods reproduce the line as it is.
+/// The indentation of a line, ex: the tab of `#[toml_example(indent_tabs)]`, is kept.
+/// ```rust
+/// use toml_example::{TomlExample, TomlExampleFormatter};
+/// struct Shout;
+/// impl TomlExampleFormatter for Shout {
+///     fn comment(&self, comment: &str) -> String {
+///         format!("#{}", comment.to_uppercase())
+///     }
+/// }
+/// #[derive(TomlExample)]
+/// struct Config {
+///     /// the port to listen
+///     port: usize,
+/// }
+/// assert_eq!(Config::toml_example_with_formatter(&Shout), "# THE PORT TO LISTEN\nport = 0\n\n");
+/// ```
+pub trait TomlExampleFormatter {
+    /// a comment line, the comment is the text after `#`, ex: ` the port to listen`
+    fn comment(&self, comment: &str) -> String {
+        format!("#{comment}")
+    }
+    /// a section header, ex: `[database]`, `[[services]]`, the header of a commented section, ex:
+    /// `# [database]`, is given without the `# `
+    fn section(&self, header: &str) -> String {
+        header.to_string()
+    }
+    /// a `key = value` line, the separator is between the key and the value, ex: ` = `, and the
+    /// value may be followed by an inline comment, the key of a commented line, ex: `# port = 0`,
+    /// is given without the `# `, and the following lines of a wrapped array are kept as they are
+    fn key_value(&self, key: &str, separator: &str, value: &str) -> String {
+        format!("{key}{separator}{value}")
+    }
+}
+
+/// The formatter of the current output, the example is kept as it is.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DefaultFormatter;
+
+impl TomlExampleFormatter for DefaultFormatter {}
+
+/// Render each line of the example with the formatter, a commented line of a section or a key,
+/// ex: `# [database]`, `# port = 0`, is passed without the `# ` and the comment mark is kept before
+/// the rendered line. The empty lines, and the lines inside a wrapped array or a multi-line string,
+/// are kept.
+pub(crate) fn format_example<F: TomlExampleFormatter + ?Sized>(example: &str, formatter: &F) -> String {
+    let mut open = Open::None;
+    example
+        .split_inclusive('\n')
+        .map(|l| {
+            let (line, newline) = match l.strip_suffix('\n') {
+                Some(line) => (line, "\n"),
+                None => (l, ""),
+            };
+            if open != Open::None {
+                let content = line.trim_start().trim_start_matches('#');
+                open = scan(content, open);
+                return l.to_string();
+            }
+            let body = line.trim_start();
+            let indent = &line[..line.len() - body.len()];
+            let commented = body.strip_prefix('#').map(|c| {
+                let content = c.trim_start();
+                (&body[..body.len() - content.len()], content)
+            });
+            let rendered = match commented {
+                Some((mark, content)) if is_section(content) => {
+                    format!("{mark}{}", formatter.section(content))
+                }
+                Some((mark, content)) => match split_key_value(content) {
+                    Some((key, separator, value)) if is_key(key) => {
+                        open = scan(value, Open::None);
+                        format!("{mark}{}", formatter.key_value(key, separator, value))
+                    }
+                    _ => formatter.comment(&body[1..]),
+                },
+                None if is_section(body) => formatter.section(body),
+                None => match split_key_value(body) {
+                    Some((key, separator, value)) => {
+                        open = scan(value, Open::None);
+                        formatter.key_value(key, separator, value)
+                    }
+                    None => body.to_string(),
+                },
+            };
+            format!("{indent}{rendered}{newline}")
+        })
+        .collect()
+}
+
+/// The value continues in the next line, which is in an array, an inline table or a multi-line string
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Open {
+    None,
+    Brackets(usize),
+    String(&'static str, usize),
+}
+
+/// Scan a part of a value, and return what is still open at the end of the line
+fn scan(value: &str, mut open: Open) -> Open {
+    let mut quote: Option<char> = None;
+    let mut rest = value;
+    while let Some(c) = rest.chars().next() {
+        let len = c.len_utf8();
+        match (open, quote, c) {
+            (Open::String(delimiter, depth), _, _) => {
+                if c == '\\' && delimiter == "\"\"\"" {
+                    rest = &rest[len..];
+                    if let Some(escaped) = rest.chars().next() {
+                        rest = &rest[escaped.len_utf8()..];
+                    }
+                    continue;
+                }
+                if rest.starts_with(delimiter) {
+                    open = if depth == 0 { Open::None } else { Open::Brackets(depth) };
+                    rest = &rest[delimiter.len()..];
+                    continue;
+                }
+            }
+            (_, Some('"'), '\\') => {
+                rest = &rest[len..];
+                if let Some(escaped) = rest.chars().next() {
+                    rest = &rest[escaped.len_utf8()..];
+                }
+                continue;
+            }
+            (_, Some(q), c) if c == q => quote = None,
+            (_, Some(_), _) => (),
+            (_, None, '"' | '\'') => {
+                let delimiter = if c == '"' { "\"\"\"" } else { "\'\'\'" };
+                if rest.starts_with(delimiter) {
+                    let depth = match open {
+                        Open::Brackets(depth) => depth,
+                        _ => 0,
+                    };
+                    open = Open::String(delimiter, depth);
+                    rest = &rest[delimiter.len()..];
+                    continue;
+                }
+                quote = Some(c);
+            }
+            (_, None, '[' | '{') => {
+                open = match open {
+                    Open::Brackets(depth) => Open::Brackets(depth + 1),
+                    _ => Open::Brackets(1),
+                }
+            }
+            (Open::Brackets(depth), None, ']' | '}') => {
+                open = if depth > 1 { Open::Brackets(depth - 1) } else { Open::None }
+            }
+            (_, None, '#') => break,
+            _ => (),
+        }
+        rest = &rest[len..];
+    }
+    open
+}
+
+/// A section header, ex: `[database]`, `[[services]]`
+fn is_section(line: &str) -> bool {
+    line.starts_with('[') && line.ends_with(']')
+}
+
+/// A bare, quoted or dotted key, such that a comment with `=` is not taken as a key
+fn is_key(key: &str) -> bool {
+    // the quoted parts are replaced, then each part of a dotted key is a bare key
+    let mut bare = String::new();
+    let mut quote = None;
+    for c in key.chars() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => (),
+            (None, '"' | '\'') => {
+                quote = Some(c);
+                bare.push('_');
+            }
+            (None, c) => bare.push(c),
+        }
+    }
+    quote.is_none()
+        && bare.split('.').all(|k| {
+            let k = k.trim();
+            !k.is_empty() && k.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        })
+}
+
+/// Split a `key = value` line at the first `=` outside the quoted key
+fn split_key_value(line: &str) -> Option<(&str, &str, &str)> {
+    let mut quote = None;
+    for (i, c) in line.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '=') => {
+                let key = line[..i].trim_end();
+                let value = line[i + 1..].trim_start();
+                let separator = &line[key.len()..line.len() - value.len()];
+                return (!key.is_empty()).then_some((key, separator, value));
+            }
+            _ => (),
+        }
+    }
+    None
+}
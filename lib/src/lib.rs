@@ -85,8 +85,11 @@
 //!
 //! If you want an optional field become a required field in example,
 //! place the `#[toml_example(require)]` on the field.
+//! Place `#[toml_example(require, show_none)]` to also show a commented hint for the unset state.
 //! If you want to skip some field you can use `#[toml_example(skip)]`,
-//! the `#[serde(skip)]`, `#[serde(skip_deserializing)]` also works.
+//! the `#[serde(skip)]`, `#[serde(skip_deserializing)]` also works, and `#[serde(skip_serializing)]`
+//! works on a field with `#[serde(default)]` or an `Option` field, which can be absent in the file.
+//! The `PhantomData<T>` fields are always skipped.
 //! ```rust
 //! use toml_example::TomlExample;
 //! #[derive(TomlExample)]
@@ -115,21 +118,235 @@
 //! "#)
 //! ```
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
 #[doc(hidden)]
 pub use toml_example_derive::TomlExample;
+pub mod formatter;
+pub use formatter::{DefaultFormatter, TomlExampleFormatter};
 pub mod traits;
 pub use traits::*;
+mod utils;
+#[cfg(feature = "toml")]
+pub use utils::diff_keys;
+pub use utils::LineEnding;
+#[cfg(any(feature = "toml", test))]
+pub use utils::roundtrip_check;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::utils::{
+        comment_out, format_array, format_range, format_scalar, indent_body, inline_map,
+        inline_table, strip_comments,
+    };
+    pub use alloc::string::String;
+    use alloc::string::ToString;
+
+    /// The inline example of the type
+    pub fn inline<T: crate::TomlExample>() -> String {
+        T::toml_example_inline()
+    }
+
+    /// An inline type can not have sections, because the keys of the sections would be dropped
+    /// from the inline table, ex: `assert_inline::<HasSections<{ <T as TomlExample>::HAS_SECTIONS }>>()`
+    #[diagnostic::on_unimplemented(
+        message = "a struct with nesting sections can not be inline, please use `nesting` instead of `nesting = inline`",
+        label = "the inline struct has nesting sections"
+    )]
+    pub trait Inline {}
+
+    pub struct HasSections<const B: bool>;
+
+    impl Inline for HasSections<false> {}
+
+    pub const fn assert_inline<T: Inline>() {}
+
+    /// A struct with `#[toml_example(default)]`, whose example can be rendered from the default of
+    /// the parent, otherwise the placeholders would be rendered instead of the values of the parent
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is rendered from the default of the parent, please add `#[toml_example(default)]` on `{Self}`",
+        label = "the nesting struct has no `#[toml_example(default)]`"
+    )]
+    pub trait WithDefault: crate::TomlExample {}
+
+    /// The example of a nesting struct with the values of the default of the parent
+    pub fn with_default<T: WithDefault>(label: &str, prefix: &str, default: &T) -> String {
+        T::toml_example_with_default(label, prefix, default)
+    }
+
+    /// Join the examples of `document!`, the top-level keys of all examples are placed before the
+    /// sections, otherwise the keys would belong to the last section of the previous example.
+    pub fn document(examples: &[String]) -> String {
+        let (mut keys, mut sections) = (String::new(), String::new());
+        for example in examples {
+            let (k, s) = example.split_at(first_section(example));
+            for (doc, part) in [(&mut keys, k), (&mut sections, s)] {
+                let part = part.trim_end();
+                if !part.is_empty() {
+                    doc.push_str(part);
+                    doc.push_str("\n\n");
+                }
+            }
+        }
+        keys + &sections
+    }
+
+    /// Pad the keys of the `key = value` lines, including the commented keys, such that the
+    /// separators in each table are aligned, a section header starts another table
+    pub fn align(example: &str, separator: &str) -> String {
+        let mut aligned = String::new();
+        let mut table = alloc::vec::Vec::new();
+        for line in example.split_inclusive('\n') {
+            if line.starts_with('[') || line.starts_with("# [") {
+                aligned.push_str(&align_table(&table, separator));
+                table.clear();
+            }
+            table.push(line);
+        }
+        aligned + &align_table(&table, separator)
+    }
+
+    fn align_table(lines: &[&str], separator: &str) -> String {
+        let width = lines
+            .iter()
+            .filter_map(|l| split_key(l, separator))
+            .map(|(key, _)| key.chars().count())
+            .max()
+            .unwrap_or_default();
+        lines
+            .iter()
+            .map(|l| match split_key(l, separator) {
+                Some((key, value)) => {
+                    let padding = width - key.chars().count();
+                    alloc::format!("{key}{:padding$}{separator}{value}", "")
+                }
+                None => l.to_string(),
+            })
+            .collect()
+    }
+
+    /// Split a `key = value` line, which may be commented, into the key with the comment mark and
+    /// the value, the padding of the key is dropped, a doc line is not split
+    fn split_key<'a>(line: &'a str, separator: &str) -> Option<(&'a str, &'a str)> {
+        let start = if line.starts_with("# ") { 2 } else { 0 };
+        let (mut quote, mut escaped) = (None, false);
+        for (i, c) in line[start..].char_indices() {
+            match (quote, c) {
+                _ if escaped => escaped = false,
+                (Some('"'), '\\') => escaped = true,
+                (Some(q), c) if c == q => quote = None,
+                (Some(_), _) => (),
+                (None, '"' | '\'') => quote = Some(c),
+                (None, c) if c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.') => (),
+                (None, _) if i > 0 => {
+                    let rest = line[start + i..].trim_start_matches(' ');
+                    let value = rest.strip_prefix(separator.trim_start_matches(' '))?;
+                    return Some((&line[..start + i], value));
+                }
+                _ => return None,
+            }
+        }
+        None
+    }
+
+    /// The offset of the first section header with the comments right above it, a commented header
+    /// of an optional section is also a section, which can be uncommented
+    fn first_section(example: &str) -> usize {
+        let (mut offset, mut block) = (0, 0);
+        for line in example.split_inclusive('\n') {
+            if line.starts_with('[') || line.starts_with("# [") {
+                return block;
+            }
+            offset += line.len();
+            if !line.starts_with('#') {
+                block = offset;
+            }
+        }
+        example.len()
+    }
+}
+
+/// The core compiles without std, ex: `cargo build --no-default-features --target wasm32-unknown-unknown`
+#[cfg(not(feature = "std"))]
+const _: () = {
+    use crate as toml_example;
+    use crate::TomlExample;
+
+    #[derive(TomlExample)]
+    #[allow(dead_code)]
+    struct Inner {
+        /// Inner.a should be a number
+        a: usize,
+    }
+
+    #[derive(TomlExample)]
+    #[allow(dead_code)]
+    struct Config {
+        /// Config.b is a list of string
+        b: alloc::vec::Vec<alloc::string::String>,
+        /// Config.inner is nested
+        #[toml_example(nesting)]
+        inner: Inner,
+    }
+
+    #[allow(dead_code)]
+    fn example() -> alloc::string::String {
+        document!(Config, Inner)
+    }
+};
+
+/// Generate one toml example document from the examples of multiple structs,
+/// each example is separated by a blank line.
+///
+/// The top-level keys of all structs are placed before the nesting sections, such that the keys
+/// of a struct do not belong to the last section of the previous struct.
+/// ```rust
+/// use toml_example::{document, TomlExample};
+/// #[derive(TomlExample)]
+/// struct Server {
+///     /// port should be a number
+///     port: usize,
+/// }
+/// #[derive(TomlExample)]
+/// struct Logging {
+///     /// level of logging
+///     #[toml_example(default = "info")]
+///     level: String,
+/// }
+/// assert_eq!(document!(Server, Logging),
+/// r#"# port should be a number
+/// port = 0
+///
+/// ## level of logging
+/// level = "info"
+///
+/// "#);
+/// ```
+#[macro_export]
+macro_rules! document {
+    ($($ty:ty),+ $(,)?) => {
+        $crate::__private::document(&[$(<$ty as $crate::TomlExample>::toml_example()),+])
+    };
+}
 
 #[cfg(test)]
 mod tests {
+    #[cfg(not(feature = "std"))]
+    extern crate std;
+    #[cfg(not(feature = "std"))]
+    use std::{boxed::Box, string::String, string::ToString, vec, vec::Vec};
+
     use crate as toml_example;
     use serde_derive::Deserialize;
     use std::collections::HashMap;
     use toml_example::TomlExample;
 
     #[test]
+    #[allow(clippy::needless_borrow)]
     fn basic() {
-        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[derive(TomlExample, Deserialize, serde_derive::Serialize, Default, PartialEq, Debug)]
         #[allow(dead_code)]
         struct Config {
             /// Config.a should be a number
@@ -151,18 +368,106 @@ b = ""
             toml::from_str::<Config>(&Config::toml_example()).unwrap(),
             Config::default()
         );
-        let mut tmp_file = std::env::temp_dir();
-        tmp_file.push("config.toml");
-        Config::to_toml_example(&tmp_file.as_path().to_str().unwrap()).unwrap();
-        assert_eq!(
-            std::fs::read_to_string(tmp_file).unwrap(),
-            r#"# Config.a should be a number
+        assert!(toml_example::roundtrip_check::<Config>().is_ok());
+        #[cfg(feature = "std")]
+        {
+            let mut tmp_file = std::env::temp_dir();
+            tmp_file.push("config.toml");
+            Config::to_toml_example(&tmp_file.as_path().to_str().unwrap()).unwrap();
+            assert_eq!(
+                std::fs::read_to_string(tmp_file).unwrap(),
+                r#"# Config.a should be a number
 a = 0
 
 # Config.b should be a string
 b = ""
 
 "#
+            );
+        }
+    }
+
+    /// The core of the example generation only needs alloc,
+    /// run with `cargo test --no-default-features --features serde`
+    #[cfg(not(feature = "std"))]
+    #[test]
+    fn no_std() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Inner {
+            /// Inner.a should be a number
+            a: usize,
+        }
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.b is a list of string
+            #[toml_example(default = ["x"])]
+            b: Vec<String>,
+            /// Config.inner is nested
+            #[toml_example(nesting)]
+            inner: Inner,
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.b is a list of string
+b = [ "x", ]
+
+# Config.inner is nested
+[inner]
+# Inner.a should be a number
+a = 0
+
+"#
+        );
+        assert_eq!(Config::toml_example_keys(), vec!["b", "inner"]);
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn toml_example_diff() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Inner {
+            a: usize,
+        }
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.name should be a string
+            name: String,
+            /// Config.timeout is a new option
+            timeout: usize,
+            /// Config.retries is optional
+            retries: Option<usize>,
+            /// Config.inner is nested
+            #[toml_example(nesting)]
+            inner: Inner,
+        }
+        let current = r#"name = "app"
+
+[inner]
+a = 1
+"#;
+        assert_eq!(Config::toml_example_diff(current), "+ timeout\n");
+        assert_eq!(Config::toml_example_diff(&Config::toml_example()), "");
+        assert!(Config::toml_example_diff("name = ").starts_with("invalid config"));
+    }
+
+    #[test]
+    fn roundtrip_check() {
+        use serde::Serialize;
+
+        #[derive(TomlExample, Deserialize, Serialize)]
+        #[allow(dead_code)]
+        struct Mismatch {
+            #[toml_example(default = 7)]
+            #[serde(rename(serialize = "b"))]
+            a: usize,
+        }
+        assert_eq!(
+            toml_example::roundtrip_check::<Mismatch>(),
+            Err("a: 7 in example, missing after round trip".to_string())
         );
     }
 
@@ -192,6 +497,39 @@ b = ""
         )
     }
 
+    #[test]
+    fn double_option() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.a is absent, null or a number
+            a: Option<Option<usize>>,
+            /// Config.b is absent, null or a list of number
+            b: Option<Option<Vec<usize>>>,
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.a is absent, null or a number
+# a = 0
+
+# Config.b is absent, null or a list of number
+# b = [ 0, ]
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config::default()
+        );
+        assert_eq!(
+            toml::from_str::<Config>("a = 0").unwrap(),
+            Config {
+                a: Some(Some(0)),
+                b: None
+            }
+        );
+    }
+
     #[test]
     fn vec() {
         #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
@@ -225,6 +563,98 @@ c = [ 0, ]
         assert!(toml::from_str::<Config>(&Config::toml_example()).is_ok())
     }
 
+    #[test]
+    fn wrapper() {
+        use std::cell::RefCell;
+        use std::sync::{Arc, Mutex};
+
+        /// Inner is a config live in Outer
+        #[derive(TomlExample)]
+        #[allow(dead_code)]
+        struct Inner {
+            /// Inner.a should be a number
+            a: usize,
+        }
+        #[derive(TomlExample)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.a is a shared number
+            a: Arc<Mutex<usize>>,
+            /// Config.b is a string
+            b: Box<RefCell<String>>,
+            /// Config.inner is a shared struct
+            #[toml_example(nesting)]
+            inner: Arc<Mutex<Inner>>,
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.a is a shared number
+a = 0
+
+# Config.b is a string
+b = ""
+
+# Config.inner is a shared struct
+# Inner is a config live in Outer
+[inner]
+# Inner.a should be a number
+a = 0
+
+"#
+        );
+    }
+
+    #[test]
+    fn boxed_slice() {
+        #[derive(TomlExample, Deserialize, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.a is a list of number
+            a: Box<[usize]>,
+            /// Config.b is a list of string
+            b: Box<[String]>,
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.a is a list of number
+a = [ 0, ]
+
+# Config.b is a list of string
+b = [ "", ]
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config {
+                a: vec![0].into_boxed_slice(),
+                b: vec![String::new()].into_boxed_slice(),
+            }
+        );
+    }
+
+    #[test]
+    fn reference() {
+        #[derive(TomlExample)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.name is a string
+            name: &'static str,
+            /// Config.items is a list of number
+            items: &'static [u32],
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.name is a string
+name = ""
+
+# Config.items is a list of number
+items = [ 0, ]
+
+"#
+        );
+    }
+
     #[test]
     fn struct_doc() {
         /// Config is to arrange something or change the controls on a computer or other device
@@ -299,548 +729,4820 @@ d = ""
     }
 
     #[test]
-    fn toml_example_default() {
-        fn default_str() -> String {
-            "seven".into()
-        }
-        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+    fn serde_default_fn_after_struct() {
+        #[derive(TomlExample, Deserialize, PartialEq, Debug)]
         #[allow(dead_code)]
         struct Config {
             /// Config.a should be a number
-            #[toml_example(default = 7)]
+            #[serde(default = "default_a")]
             a: usize,
             /// Config.b should be a string
-            #[toml_example(default = "default")]
-            #[serde(default = "default_str")]
+            #[serde(default = "default_b")]
             b: String,
-            #[serde(default = "default_str")]
-            #[toml_example(default = "default")]
-            c: String,
-            #[toml_example(default = [ "default", ])]
-            e: Vec<String>,
-            #[toml_example(
-                default = "super looooooooooooooooooooooooooooooooooooooooooooooooooooooooooooong string"
-            )]
-            f: String,
-            #[toml_example(default = [ "super looooooooooooooooooooooooooooooooooooooooooooooooooooooooooooong string",
-               "second",
-               "third",
-            ])]
-            g: Vec<String>,
-            /// Config.color should be a hex color code
-            #[toml_example(default = "#FAFAFA")]
-            color: String,
         }
         assert_eq!(
             Config::toml_example(),
-            r##"# Config.a should be a number
+            r#"# Config.a should be a number
 a = 7
 
 # Config.b should be a string
-b = "seven"
-
-c = "default"
+b = "forward"
 
-e = ["default",]
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config {
+                a: default_a(),
+                b: default_b(),
+            }
+        );
+    }
 
-f = "super looooooooooooooooooooooooooooooooooooooooooooooooooooooooooooong string"
+    // the default functions are declared after the struct deriving `TomlExample`
+    fn default_a() -> usize {
+        7
+    }
 
-g = ["super looooooooooooooooooooooooooooooooooooooooooooooooooooooooooooong string",
-"second", "third",]
+    fn default_b() -> String {
+        "forward".into()
+    }
 
-# Config.color should be a hex color code
-color = "#FAFAFA"
+    #[test]
+    fn front_matter() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Page {
+            /// Page.title should be a string
+            title: String,
+            /// Page.weight should be a number
+            weight: usize,
+        }
+        let front_matter = Page::toml_example_front_matter();
+        assert_eq!(
+            front_matter,
+            r#"+++
+# Page.title should be a string
+title = ""
 
-"##
+# Page.weight should be a number
+weight = 0
++++"#
         );
+        let body = front_matter
+            .strip_prefix("+++\n")
+            .and_then(|s| s.strip_suffix("\n+++"))
+            .unwrap();
+        assert_eq!(toml::from_str::<Page>(body).unwrap(), Page::default());
     }
 
+    #[cfg(feature = "smallvec")]
     #[test]
-    fn no_nesting() {
-        /// Inner is a config live in Outer
+    fn smallvec() {
+        use smallvec::{smallvec, SmallVec};
         #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
         #[allow(dead_code)]
-        struct Inner {
-            /// Inner.a should be a number
-            a: usize,
+        struct Peer {
+            /// Peer.host should be a string
+            host: String,
         }
         #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
         #[allow(dead_code)]
-        struct Outer {
-            /// Outer.inner is a complex struct
-            inner: Inner,
+        struct Config {
+            /// Config.ports is a list of number
+            ports: SmallVec<[u16; 4]>,
+            #[toml_example(default = [80, 443])]
+            defaults: SmallVec<[u16; 4]>,
+            #[toml_example(nesting)]
+            peers: SmallVec<[Peer; 2]>,
         }
         assert_eq!(
-            Outer::toml_example(),
-            r#"# Outer.inner is a complex struct
-inner = ""
+            Config::toml_example(),
+            r#"# Config.ports is a list of number
+ports = [ 0, ]
+
+defaults = [ 80, 443, ]
+
+[[peers]]
+# Peer.host should be a string
+host = ""
 
 "#
         );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config {
+                ports: smallvec![0],
+                defaults: smallvec![80, 443],
+                peers: smallvec![Peer::default()],
+            }
+        );
     }
 
+    #[cfg(feature = "arrayvec")]
     #[test]
-    fn nesting() {
-        /// Inner is a config live in Outer
+    fn arrayvec() {
+        use arrayvec::{ArrayString, ArrayVec};
         #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
         #[allow(dead_code)]
-        struct Inner {
-            /// Inner.a should be a number
-            a: usize,
+        struct Peer {
+            /// Peer.host should be a string
+            host: ArrayString<16>,
         }
         #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[toml_example(type_hints)]
         #[allow(dead_code)]
-        struct Outer {
-            /// Outer.inner is a complex struct
+        struct Config {
+            name: ArrayString<8>,
+            ports: ArrayVec<u16, 4>,
+            #[toml_example(default = [80, 443])]
+            defaults: ArrayVec<u16, 4>,
             #[toml_example(nesting)]
-            inner: Inner,
+            peers: ArrayVec<Peer, 2>,
         }
         assert_eq!(
-            Outer::toml_example(),
-            r#"# Outer.inner is a complex struct
-# Inner is a config live in Outer
-[inner]
-# Inner.a should be a number
-a = 0
+            Config::toml_example(),
+            r#"# (string)
+name = ""
+
+# (array of integers)
+ports = [ 0, ]
+
+# (array of integers)
+defaults = [ 80, 443, ]
+
+[[peers]]
+# Peer.host should be a string
+host = ""
 
 "#
         );
-        assert_eq!(
-            toml::from_str::<Outer>(&Outer::toml_example()).unwrap(),
-            Outer::default()
-        );
+        let config = toml::from_str::<Config>(&Config::toml_example()).unwrap();
+        assert_eq!(config.ports.as_slice(), &[0]);
+        assert_eq!(config.defaults.as_slice(), &[80, 443]);
+        assert_eq!(config.peers.as_slice(), &[Peer::default()]);
     }
 
+    #[cfg(feature = "bytes")]
     #[test]
-    fn nesting_by_section() {
-        /// Inner is a config live in Outer
-        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
-        #[allow(dead_code)]
-        struct Inner {
-            /// Inner.a should be a number
-            a: usize,
+    fn bytes() {
+        use bytes::{Bytes, BytesMut};
+        fn default_token() -> Bytes {
+            Bytes::from_static(b"secret")
         }
         #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[toml_example(type_hints, strict)]
         #[allow(dead_code)]
-        struct Outer {
-            /// Outer.inner is a complex struct
-            #[toml_example(nesting = section)]
-            inner: Inner,
+        struct Config {
+            payload: Bytes,
+            #[toml_example(default)]
+            buffer: BytesMut,
+            #[serde(default = "default_token")]
+            token: Bytes,
         }
         assert_eq!(
-            Outer::toml_example(),
-            r#"# Outer.inner is a complex struct
-# Inner is a config live in Outer
-[inner]
-# Inner.a should be a number
-a = 0
+            Config::toml_example(),
+            r#"# (string)
+payload = ""
+
+# (string)
+buffer = ""
+
+# (string)
+token = "secret"
 
 "#
         );
         assert_eq!(
-            toml::from_str::<Outer>(&Outer::toml_example()).unwrap(),
-            Outer::default()
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config {
+                token: default_token(),
+                ..Default::default()
+            }
         );
     }
 
+    #[cfg(feature = "std")]
     #[test]
-    fn nesting_by_prefix() {
-        /// Inner is a config live in Outer
+    fn write_toml_example() {
         #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
         #[allow(dead_code)]
-        struct Inner {
-            /// Inner.a should be a number
+        struct Config {
+            /// Config.a should be a number
             a: usize,
         }
-        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        let mut buffer = Vec::new();
+        Config::write_toml_example(&mut buffer).unwrap();
+        assert_eq!(String::from_utf8(buffer).unwrap(), Config::toml_example());
+    }
+
+    #[cfg(feature = "validator")]
+    #[test]
+    fn validator() {
+        use validator::Validate;
+        #[derive(TomlExample, Deserialize, Validate, Default, PartialEq, Debug)]
         #[allow(dead_code)]
-        struct Outer {
-            /// Outer.inner is a complex struct
-            #[toml_example(nesting = prefix)]
-            inner: Inner,
+        struct Config {
+            /// Config.workers is the number of workers
+            #[validate(range(min = 1, max = 10))]
+            #[toml_example(default = 4)]
+            workers: usize,
+            #[validate(range(min = 1))]
+            #[toml_example(default = 1)]
+            retries: usize,
+            #[validate(length(min = 3, max = 20))]
+            #[toml_example(default = "admin")]
+            user: String,
+            #[validate(length(equal = 2))]
+            #[toml_example(default = "us")]
+            region: String,
+            /// Config.contact is notified on failures
+            #[validate(email)]
+            #[toml_example(default = "ops@example.com")]
+            contact: String,
         }
         assert_eq!(
-            Outer::toml_example(),
-            r#"# Outer.inner is a complex struct
-# Inner is a config live in Outer
-# Inner.a should be a number
-inner.a = 0
+            Config::toml_example(),
+            r#"# Config.workers is the number of workers
+# must be between 1 and 10
+workers = 4
+
+# must be at least 1
+retries = 1
+
+# length must be between 3 and 20
+user = "admin"
+
+# length must be 2
+region = "us"
+
+# Config.contact is notified on failures
+# must be an email
+contact = "ops@example.com"
 
 "#
         );
-        assert_eq!(
-            toml::from_str::<Outer>(&Outer::toml_example()).unwrap(),
-            Outer::default()
-        );
+        let config = toml::from_str::<Config>(&Config::toml_example()).unwrap();
+        assert!(config.validate().is_ok());
     }
 
     #[test]
-    fn nesting_vector() {
-        /// Service with specific port
-        #[derive(TomlExample, Deserialize)]
+    fn schema_version() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[toml_example(version = "1.2")]
         #[allow(dead_code)]
-        struct Service {
-            /// port should be a number
-            port: usize,
+        struct Database {
+            /// Database.url should be a string
+            url: String,
         }
-        #[derive(TomlExample, Deserialize)]
+        /// Config is to arrange something
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[toml_example(version = "1.2")]
         #[allow(dead_code)]
-        struct Node {
-            /// Services are running in the node
+        struct Config {
+            /// Config.a should be a number
+            a: usize,
             #[toml_example(nesting)]
-            services: Vec<Service>,
+            database: Database,
         }
+        let example = Config::toml_example();
+        assert!(example.starts_with("# schema version: 1.2\n"));
         assert_eq!(
-            Node::toml_example(),
-            r#"# Services are running in the node
-# Service with specific port
-[[services]]
-# port should be a number
-port = 0
+            example,
+            r#"# schema version: 1.2
+
+# Config is to arrange something
+# Config.a should be a number
+a = 0
+
+[database]
+# Database.url should be a string
+url = ""
 
 "#
         );
-        assert!(toml::from_str::<Node>(&Node::toml_example()).is_ok());
+        assert_eq!(toml::from_str::<Config>(&example).unwrap(), Config::default());
     }
 
     #[test]
-    fn nesting_hashmap() {
-        /// Service with specific port
-        #[derive(TomlExample, Deserialize)]
+    fn formatter() {
+        use crate::{DefaultFormatter, TomlExampleFormatter};
+        struct UppercaseComments;
+        impl TomlExampleFormatter for UppercaseComments {
+            fn comment(&self, comment: &str) -> String {
+                std::format!("#{}", comment.to_uppercase())
+            }
+        }
+        struct Compact;
+        impl TomlExampleFormatter for Compact {
+            fn key_value(&self, key: &str, _separator: &str, value: &str) -> String {
+                std::format!("{key}={value}")
+            }
+        }
+
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
         #[allow(dead_code)]
-        struct Service {
-            /// port should be a number
-            port: usize,
+        struct Database {
+            /// Database.url should be a string
+            url: String,
         }
-        #[derive(TomlExample, Deserialize)]
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
         #[allow(dead_code)]
-        struct Node {
-            /// Services are running in the node
+        struct Config {
+            /// Config.a should be a number
+            a: usize,
+            #[toml_example(default = [1, 2])]
+            b: Vec<usize>,
             #[toml_example(nesting)]
-            services: HashMap<String, Service>,
+            database: Database,
+            /// Config.backups are optional
+            backups: Option<usize>,
+            /// Config.hosts wraps
+            #[toml_example(width = 10, default = ["a", "b"])]
+            hosts: Vec<String>,
+            #[toml_example(nesting)]
+            replica: Option<Database>,
         }
         assert_eq!(
-            Node::toml_example(),
-            r#"# Services are running in the node
-# Service with specific port
-[services.example]
-# port should be a number
-port = 0
+            Config::toml_example_with_formatter(&DefaultFormatter),
+            Config::toml_example()
+        );
+        let example = Config::toml_example_with_formatter(&UppercaseComments);
+        assert_eq!(
+            example,
+            r#"# CONFIG.A SHOULD BE A NUMBER
+a = 0
+
+b = [ 1, 2, ]
+
+# CONFIG.BACKUPS ARE OPTIONAL
+# backups = 0
+
+# CONFIG.HOSTS WRAPS
+hosts = [
+    "a",
+    "b",
+]
+
+[database]
+# DATABASE.URL SHOULD BE A STRING
+url = ""
+
+# [replica]
+# DATABASE.URL SHOULD BE A STRING
+# url = ""
 
 "#
         );
-        assert!(toml::from_str::<Node>(&Node::toml_example()).is_ok());
+        assert_eq!(
+            toml::from_str::<Config>(&example).unwrap(),
+            Config {
+                b: vec![1, 2],
+                hosts: vec!["a".to_string(), "b".to_string()],
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            Config::toml_example_with_formatter(&Compact),
+            r#"# Config.a should be a number
+a=0
+
+b=[ 1, 2, ]
+
+# Config.backups are optional
+# backups=0
+
+# Config.hosts wraps
+hosts=[
+    "a",
+    "b",
+]
+
+[database]
+# Database.url should be a string
+url=""
+
+# [replica]
+# Database.url should be a string
+# url=""
+
+"#
+        );
+        // the lines of a multi-line string are not keys or sections
+        assert_eq!(
+            crate::formatter::format_example("s = \"\"\"\n[a]\nb = 1\n\"\"\"\nc = 2\n", &Compact),
+            "s=\"\"\"\n[a]\nb = 1\n\"\"\"\nc=2\n"
+        );
     }
 
     #[test]
-    fn optional_nesting() {
-        /// Inner is a config live in Outer
+    fn reference_document() {
         #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
         #[allow(dead_code)]
-        struct Inner {
-            /// Inner.a should be a number
-            a: usize,
+        struct Database {
+            /// Database.url should be a string
+            url: String,
         }
         #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
         #[allow(dead_code)]
-        struct Outer {
-            /// Outer.inner is a complex struct
+        struct Config {
+            /// Config.a should be a number
+            a: usize,
+            /// Config.b is optional
+            b: Option<String>,
             #[toml_example(nesting)]
-            inner: Option<Inner>,
+            database: Database,
         }
+        let reference = Config::toml_example_reference();
         assert_eq!(
-            Outer::toml_example(),
-            r#"# Outer.inner is a complex struct
-# Inner is a config live in Outer
-# [inner]
-# Inner.a should be a number
+            reference,
+            r#"# Config.a should be a number
 # a = 0
 
+# Config.b is optional
+# b = ""
+
+# [database]
+# Database.url should be a string
+# url = ""
+
 "#
         );
+        assert!(reference.lines().all(|l| l.is_empty() || l.starts_with('#')));
         assert_eq!(
-            toml::from_str::<Outer>(&Outer::toml_example()).unwrap(),
-            Outer::default()
+            toml::from_str::<toml::Table>(&reference).unwrap(),
+            toml::Table::new()
         );
     }
 
     #[test]
-    fn optional_nesting_by_section() {
-        /// Inner is a config live in Outer
+    fn unicode_default() {
+        fn default_greeting() -> String {
+            "¡hola, señor! 👋".into()
+        }
         #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
         #[allow(dead_code)]
-        struct Inner {
-            /// Inner.a should be a number
-            a: usize,
+        struct Config {
+            /// Config.drink is served in the café ☕
+            #[toml_example(default = "café ☕")]
+            drink: String,
+            #[toml_example(default = ["naïve, déjà vu", "🦀", "日本語"])]
+            words: Vec<String>,
+            #[serde(default = "default_greeting")]
+            greeting: String,
         }
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.drink is served in the café ☕
+drink = "café ☕"
+
+words = [ "naïve, déjà vu", "🦀", "日本語", ]
+
+greeting = "¡hola, señor! 👋"
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config {
+                drink: "café ☕".into(),
+                words: vec!["naïve, déjà vu".into(), "🦀".into(), "日本語".into()],
+                greeting: default_greeting(),
+            }
+        );
+    }
+
+    #[test]
+    fn negative_float_array() {
         #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
         #[allow(dead_code)]
-        struct Outer {
-            /// Outer.inner is a complex struct
-            #[toml_example(nesting = section)]
-            inner: Option<Inner>,
+        struct Config {
+            #[toml_example(default = [-1.5, 2.0, -3.25])]
+            a: Vec<f64>,
+            #[toml_example(default = [-1.5f32, +2., -3e2])]
+            b: Vec<f32>,
         }
         assert_eq!(
-            Outer::toml_example(),
-            r#"# Outer.inner is a complex struct
-# Inner is a config live in Outer
-# [inner]
-# Inner.a should be a number
-# a = 0
+            Config::toml_example(),
+            r#"a = [ -1.5, 2.0, -3.25, ]
+
+b = [ -1.5, +2.0, -3e2, ]
 
 "#
         );
         assert_eq!(
-            toml::from_str::<Outer>(&Outer::toml_example()).unwrap(),
-            Outer::default()
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config {
+                a: vec![-1.5, 2.0, -3.25],
+                b: vec![-1.5, 2.0, -300.0],
+            }
         );
     }
 
     #[test]
-    fn optional_nesting_by_prefix() {
-        /// Inner is a config live in Outer
+    fn char_default() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            #[toml_example(default = 'Z')]
+            a: char,
+            #[toml_example(default = '\n')]
+            b: char,
+            #[toml_example(default = ',')]
+            c: char,
+            #[toml_example(default = '"')]
+            d: char,
+            #[toml_example(default = ['\t', '\\'])]
+            e: Vec<char>,
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"a = "Z"
+
+b = "\n"
+
+c = ","
+
+d = "\""
+
+e = [ "\t", "\\", ]
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config {
+                a: 'Z',
+                b: '\n',
+                c: ',',
+                d: '"',
+                e: vec!['\t', '\\'],
+            }
+        );
+    }
+
+    #[test]
+    fn array_trailing_comma() {
+        fn default_d() -> Vec<String> {
+            vec!["x".into(), "y".into()]
+        }
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            #[toml_example(default = ["a", "b",])]
+            a: Vec<String>,
+            #[toml_example(default = ["a", "b"])]
+            b: Vec<String>,
+            #[toml_example(default)]
+            c: Vec<String>,
+            #[serde(default = "default_d")]
+            d: Vec<String>,
+            e: [usize; 2],
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"a = [ "a", "b", ]
+
+b = [ "a", "b", ]
+
+c = [ ]
+
+d = [ "x", "y", ]
+
+e = [ 0, 0, ]
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config {
+                a: vec!["a".into(), "b".into()],
+                b: vec!["a".into(), "b".into()],
+                c: Vec::new(),
+                d: default_d(),
+                e: [0, 0],
+            }
+        );
+    }
+
+    #[test]
+    fn deny_unknown_fields_with_flatten() {
         #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
         #[allow(dead_code)]
         struct Inner {
-            /// Inner.a should be a number
-            a: usize,
+            /// Inner.b should be a number
+            b: usize,
         }
+        // serde does not support the combination, a warning is commented above the flattened keys,
+        // the flattened keys are accepted, and the other unknown keys are still denied
         #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[serde(deny_unknown_fields)]
         #[allow(dead_code)]
         struct Outer {
-            /// Outer.inner is a complex struct
-            #[toml_example(nesting = prefix)]
-            inner: Option<Inner>,
+            /// Outer.a should be a number
+            a: usize,
+            #[serde(flatten)]
+            inner: Inner,
         }
         assert_eq!(
             Outer::toml_example(),
-            r#"# Outer.inner is a complex struct
-# Inner is a config live in Outer
-# Inner.a should be a number
-# inner.a = 0
+            r#"# Outer.a should be a number
+a = 0
+
+# WARNING: serde does not support `deny_unknown_fields` with `flatten`, the following keys are flattened
+# Inner.b should be a number
+b = 0
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Outer>(&Outer::toml_example()).unwrap(),
+            Outer::default()
+        );
+        assert!(toml::from_str::<Outer>("a = 0\nb = 0\nc = 0\n").is_err());
+    }
+
+    #[test]
+    fn range() {
+        use std::ops::{Range, RangeInclusive};
+        #[derive(TomlExample, Deserialize, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.a is a range
+            a: Range<usize>,
+            #[toml_example(default = 0..10)]
+            b: Range<usize>,
+            #[toml_example(default = "1..=5")]
+            c: RangeInclusive<u8>,
+            #[toml_example(default)]
+            d: Range<i32>,
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.a is a range
+a = { start = 0, end = 0 }
+
+b = { start = 0, end = 10 }
+
+c = { start = 1, end = 5 }
+
+d = { start = 0, end = 0 }
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config {
+                a: 0..0,
+                b: 0..10,
+                c: 1..=5,
+                d: 0..0,
+            }
+        );
+    }
+
+    #[test]
+    fn map_key_comment() {
+        use std::collections::HashMap;
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Service {
+            port: usize,
+        }
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.services are the services to run
+            #[toml_example(nesting, map_key_comment = "replace 'example' with your service name")]
+            services: HashMap<String, Service>,
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.services are the services to run
+# replace 'example' with your service name
+[services.example]
+port = 0
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config {
+                services: HashMap::from([("example".to_string(), Service::default())]),
+            }
+        );
+    }
+
+    #[test]
+    fn toml_example_default() {
+        fn default_str() -> String {
+            "seven".into()
+        }
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.a should be a number
+            #[toml_example(default = 7)]
+            a: usize,
+            /// Config.b should be a string
+            #[toml_example(default = "default")]
+            #[serde(default = "default_str")]
+            b: String,
+            #[serde(default = "default_str")]
+            #[toml_example(default = "default")]
+            c: String,
+            #[toml_example(default = [ "default", ])]
+            e: Vec<String>,
+            #[toml_example(
+                default = "super looooooooooooooooooooooooooooooooooooooooooooooooooooooooooooong string"
+            )]
+            f: String,
+            #[toml_example(default = [ "super looooooooooooooooooooooooooooooooooooooooooooooooooooooooooooong string",
+               "second",
+               "third",
+            ])]
+            g: Vec<String>,
+            /// Config.color should be a hex color code
+            #[toml_example(default = "#FAFAFA")]
+            color: String,
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r##"# Config.a should be a number
+a = 7
+
+# Config.b should be a string
+b = "seven"
+
+c = "default"
+
+e = [ "default", ]
+
+f = "super looooooooooooooooooooooooooooooooooooooooooooooooooooooooooooong string"
+
+g = [ "super looooooooooooooooooooooooooooooooooooooooooooooooooooooooooooong string", "second", "third", ]
+
+# Config.color should be a hex color code
+color = "#FAFAFA"
+
+"##
+        );
+    }
+
+    #[test]
+    fn array_style() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Spaced {
+            a: Vec<usize>,
+            #[toml_example(default = [1, 2])]
+            b: Vec<usize>,
+            #[toml_example(default = [[1], [2, 3,]])]
+            c: Vec<Vec<usize>>,
+        }
+        assert_eq!(
+            Spaced::toml_example(),
+            r#"a = [ 0, ]
+
+b = [ 1, 2, ]
+
+c = [ [ 1, ], [ 2, 3, ], ]
+
+"#
+        );
+        assert!(toml::from_str::<Spaced>(&Spaced::toml_example()).is_ok());
+
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[toml_example(array_style = "compact")]
+        #[allow(dead_code)]
+        struct Compact {
+            a: Vec<usize>,
+            #[toml_example(default = [ 1, 2, ])]
+            b: Vec<usize>,
+            #[toml_example(default = [[1], [2, 3,]])]
+            c: Vec<Vec<usize>>,
+        }
+        assert_eq!(
+            Compact::toml_example(),
+            r#"a = [0]
+
+b = [1, 2]
+
+c = [[1], [2, 3]]
+
+"#
+        );
+        assert!(toml::from_str::<Compact>(&Compact::toml_example()).is_ok());
+    }
+
+    #[test]
+    fn kv_separator() {
+        fn default_b() -> String {
+            "b".into()
+        }
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[toml_example(kv_separator = "=")]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.a should be a number
+            a: usize,
+            #[serde(default = "default_b")]
+            b: String,
+            c: Option<Vec<usize>>,
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.a should be a number
+a=0
+
+b="b"
+
+# c=[ 0, ]
+
+"#
+        );
+        assert!(toml::from_str::<Config>(&Config::toml_example()).is_ok());
+    }
+
+    #[test]
+    fn tuple() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.a is a pair
+            a: (usize, String),
+            /// Config.pair is a pair with default
+            #[toml_example(default = (1, "a"))]
+            pair: (usize, String),
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.a is a pair
+a = [ 0, "", ]
+
+# Config.pair is a pair with default
+pair = [ 1, "a", ]
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config {
+                a: (0, "".into()),
+                pair: (1, "a".into()),
+            }
+        );
+    }
+
+    #[test]
+    fn large_integer() {
+        #[derive(TomlExample)]
+        #[allow(dead_code)]
+        struct Unsigned {
+            /// u64::MAX is out of the range of toml integer
+            #[toml_example(default = 18446744073709551615)]
+            a: u64,
+        }
+        assert_eq!(
+            Unsigned::toml_example(),
+            r#"# u64::MAX is out of the range of toml integer
+a = 18446744073709551615
+
+"#
+        );
+
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Signed {
+            #[toml_example(default = 9223372036854775807)]
+            a: i64,
+            #[toml_example(default = -9223372036854775808)]
+            b: i64,
+            #[toml_example(default = 9_223_372_036_854_775_807u64)]
+            c: u64,
+        }
+        assert_eq!(
+            Signed::toml_example(),
+            r#"a = 9223372036854775807
+
+b = -9223372036854775808
+
+c = 9223372036854775807
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Signed>(&Signed::toml_example()).unwrap(),
+            Signed {
+                a: i64::MAX,
+                b: i64::MIN,
+                c: i64::MAX as u64,
+            }
+        );
+    }
+
+    #[test]
+    fn toml_example_default_fn() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            #[toml_example(default)]
+            a: String,
+            #[toml_example(default)]
+            b: usize,
+            #[toml_example(default)]
+            c: bool,
+            #[toml_example(default)]
+            d: Vec<usize>,
+            #[toml_example(default)]
+            e: Option<Vec<String>>,
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"a = ""
+
+b = 0
+
+c = false
+
+d = [ ]
+
+# e = [ ]
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config::default()
+        );
+    }
+
+    #[test]
+    fn tuple_struct() {
+        /// Rgb color
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        struct Rgb(u8, u8, u8);
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        struct Port(#[toml_example(default = 80)] u16);
+        /// Server setting
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        struct Server {
+            /// Server.host should be a string
+            host: String,
+            /// Server.retry should be a number
+            retry: usize,
+        }
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        struct Config {
+            /// Config.color is a color
+            #[toml_example(nesting = inline)]
+            color: Rgb,
+            #[toml_example(nesting = inline)]
+            port: Port,
+            #[toml_example(nesting = inline)]
+            server: Server,
+        }
+        assert_eq!(Rgb::toml_example_inline(), "[ 0, 0, 0, ]");
+        assert_eq!(Port::toml_example_inline(), "80");
+        // the example document of a tuple struct only has the value in a comment
+        assert_eq!(Rgb::toml_example(), "# Rgb color\n# [ 0, 0, 0, ]\n");
+        assert!(toml::from_str::<toml::Table>(&Rgb::toml_example()).unwrap().is_empty());
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.color is a color
+color = [ 0, 0, 0, ]
+
+port = 80
+
+server = { host = "", retry = 0 }
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config {
+                port: Port(80),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn no_nesting() {
+        /// Inner is a config live in Outer
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Inner {
+            /// Inner.a should be a number
+            a: usize,
+        }
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Outer {
+            /// Outer.inner is a complex struct
+            inner: Inner,
+        }
+        assert_eq!(
+            Outer::toml_example(),
+            r#"# Outer.inner is a complex struct
+inner = ""
+
+"#
+        );
+    }
+
+    #[test]
+    fn no_nesting_inline_table_default() {
+        #[derive(Deserialize, Default, PartialEq, Debug)]
+        struct Inner {
+            port: usize,
+            host: String,
+        }
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        struct Outer {
+            /// Outer.inner is a complex struct
+            #[toml_example(default = { port = 8080, host = "localhost" })]
+            inner: Inner,
+        }
+        assert_eq!(
+            Outer::toml_example(),
+            r#"# Outer.inner is a complex struct
+inner = { port = 8080, host = "localhost" }
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Outer>(&Outer::toml_example()).unwrap(),
+            Outer {
+                inner: Inner {
+                    port: 8080,
+                    host: "localhost".into(),
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn document() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        struct Config {
+            /// Config.name is a string
+            name: String,
+        }
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        struct Database {
+            /// Database.url is a string
+            url: String,
+        }
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        struct Logging {
+            /// Logging.level is a number
+            level: usize,
+        }
+        #[derive(Deserialize, Default, PartialEq, Debug)]
+        struct All {
+            name: String,
+            url: String,
+            level: usize,
+        }
+        let doc = toml_example::document!(Config, Database, Logging);
+        assert_eq!(
+            doc,
+            r#"# Config.name is a string
+name = ""
+
+# Database.url is a string
+url = ""
+
+# Logging.level is a number
+level = 0
+
+"#
+        );
+        assert_eq!(toml::from_str::<All>(&doc).unwrap(), All::default());
+    }
+
+    #[test]
+    fn document_with_sections() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        struct Tls {
+            /// Tls.cert is a path
+            cert: String,
+        }
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        struct Server {
+            /// Server.port is a number
+            port: usize,
+            /// Server.tls is nested
+            #[toml_example(nesting)]
+            tls: Tls,
+        }
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        struct Storage {
+            /// Storage.path is a path
+            path: String,
+        }
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        struct Logging {
+            /// Logging.level is a number
+            level: usize,
+            #[toml_example(nesting)]
+            storage: Option<Storage>,
+        }
+        #[derive(Deserialize, Default, PartialEq, Debug)]
+        struct All {
+            port: usize,
+            level: usize,
+            tls: Tls,
+            storage: Option<Storage>,
+        }
+        let doc = toml_example::document!(Server, Logging);
+        assert_eq!(
+            doc,
+            r#"# Server.port is a number
+port = 0
+
+# Logging.level is a number
+level = 0
+
+# Server.tls is nested
+[tls]
+# Tls.cert is a path
+cert = ""
+
+# [storage]
+# Storage.path is a path
+# path = ""
+
+"#
+        );
+        assert_eq!(toml::from_str::<All>(&doc).unwrap(), All::default());
+    }
+
+    #[test]
+    fn nesting() {
+        /// Inner is a config live in Outer
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Inner {
+            /// Inner.a should be a number
+            a: usize,
+        }
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Outer {
+            /// Outer.inner is a complex struct
+            #[toml_example(nesting)]
+            inner: Inner,
+        }
+        assert_eq!(
+            Outer::toml_example(),
+            r#"# Outer.inner is a complex struct
+# Inner is a config live in Outer
+[inner]
+# Inner.a should be a number
+a = 0
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Outer>(&Outer::toml_example()).unwrap(),
+            Outer::default()
+        );
+    }
+
+    #[test]
+    fn serde_flatten() {
+        /// Inner is a config live in Outer
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Inner {
+            /// Inner.a should be a number
+            a: usize,
+        }
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Outer {
+            /// Outer.inner is flattened
+            #[serde(flatten)]
+            inner: Inner,
+            /// Outer.b should be a number
+            b: usize,
+        }
+        assert_eq!(
+            Outer::toml_example(),
+            r#"# Outer.b should be a number
+b = 0
+
+# Outer.inner is flattened
+# Inner is a config live in Outer
+# Inner.a should be a number
+a = 0
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Outer>(&Outer::toml_example()).unwrap(),
+            Outer::default()
+        );
+    }
+
+    #[test]
+    fn serde_flatten_option() {
+        /// Inner is an optional group of keys
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Inner {
+            /// Inner.a should be a number
+            a: usize,
+            /// Inner.c should be a string
+            c: String,
+        }
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Outer {
+            /// Outer.inner is flattened
+            #[toml_example(flatten, nesting)]
+            #[serde(flatten)]
+            inner: Option<Inner>,
+            /// Outer.b should be a number
+            b: usize,
+        }
+        assert_eq!(
+            Outer::toml_example(),
+            r#"# Outer.b should be a number
+b = 0
+
+# Outer.inner is flattened
+# Inner is an optional group of keys
+# Inner.a should be a number
+# a = 0
+
+# Inner.c should be a string
+# c = ""
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Outer>(&Outer::toml_example()).unwrap(),
+            Outer::default()
+        );
+        assert_eq!(
+            toml::from_str::<Outer>(&Outer::toml_example().replace("# a", "a").replace("# c", "c")).unwrap(),
+            Outer {
+                inner: Some(Inner::default()),
+                b: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn nesting_title() {
+        /// Database is a config live in Config
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Database {
+            /// Database.url should be a string
+            url: String,
+        }
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.database is the database setting
+            #[toml_example(nesting, title = "Database Settings")]
+            database: Database,
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.database is the database setting
+# Database is a config live in Config
+# --- Database Settings ---
+[database]
+# Database.url should be a string
+url = ""
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config::default()
+        );
+    }
+
+    #[test]
+    fn serde_flatten_value() {
+        #[derive(TomlExample, Deserialize, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.a should be a number
+            a: usize,
+            /// Config.extra keeps the other keys
+            #[serde(flatten)]
+            extra: toml::Value,
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.a should be a number
+a = 0
+
+# Config.extra keeps the other keys
+# additional keys allowed here
+
+"#
+        );
+        assert!(toml::from_str::<Config>(&Config::toml_example()).is_ok());
+    }
+
+    #[test]
+    fn serde_flatten_map() {
+        use std::collections::HashMap;
+        #[derive(TomlExample, Deserialize, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.a should be a number
+            a: usize,
+            /// Config.rest keeps the other keys
+            #[serde(flatten)]
+            rest: HashMap<String, toml::Value>,
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.a should be a number
+a = 0
+
+# Config.rest keeps the other keys
+# additional keys allowed here
+# extra_key = "value"
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config {
+                a: 0,
+                rest: HashMap::new(),
+            }
+        );
+
+        #[derive(TomlExample, Deserialize, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Limits {
+            #[serde(flatten)]
+            limits: HashMap<String, usize>,
+        }
+        assert_eq!(
+            Limits::toml_example(),
+            r#"# additional keys allowed here
+# extra_key = 0
+
+"#
+        );
+        let uncommented = Limits::toml_example().replace("# extra_key", "extra_key");
+        assert_eq!(
+            toml::from_str::<Limits>(&uncommented).unwrap(),
+            Limits {
+                limits: HashMap::from([("extra_key".to_string(), 0)]),
+            }
+        );
+    }
+
+    #[test]
+    fn nesting_section_name() {
+        /// Service with specific port
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Service {
+            /// port should be a number
+            port: usize,
+        }
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Node {
+            /// Services are running in the node
+            #[toml_example(nesting, section_name = "svc")]
+            #[serde(alias = "svc")]
+            services: HashMap<String, Service>,
+        }
+        assert_eq!(
+            Node::toml_example(),
+            r#"# Services are running in the node
+# Service with specific port
+[svc.example]
+# port should be a number
+port = 0
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Node>(&Node::toml_example()).unwrap(),
+            Node {
+                services: HashMap::from([("example".to_string(), Service::default())])
+            }
+        );
+    }
+
+    #[test]
+    fn nesting_serde_rename() {
+        /// Service with specific port
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Service {
+            /// port should be a number
+            port: usize,
+        }
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Node {
+            /// The main service of the node
+            #[toml_example(nesting)]
+            #[serde(rename = "svc")]
+            service: Service,
+            /// The backup services of the node
+            #[toml_example(nesting)]
+            #[serde(rename = "backups")]
+            backup_services: Vec<Service>,
+        }
+        assert_eq!(
+            Node::toml_example(),
+            r#"# The main service of the node
+# Service with specific port
+[svc]
+# port should be a number
+port = 0
+
+# The backup services of the node
+# Service with specific port
+[[backups]]
+# port should be a number
+port = 0
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Node>(&Node::toml_example()).unwrap(),
+            Node {
+                service: Service::default(),
+                backup_services: vec![Service::default()],
+            }
+        );
+    }
+
+    #[test]
+    fn empty_template() {
+        /// Service with specific port
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Service {
+            /// port should be a number
+            #[toml_example(default = 80)]
+            port: usize,
+        }
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Node {
+            /// Zero or more services
+            #[toml_example(nesting, empty_template)]
+            #[serde(default)]
+            services: Vec<Service>,
+        }
+        assert_eq!(
+            Node::toml_example(),
+            r#"# Zero or more services
+# Service with specific port
+# [[services]]
+# port should be a number
+# port = 80
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Node>(&Node::toml_example()).unwrap(),
+            Node::default()
+        );
+
+        // the default of the struct also gives the missing array
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[serde(default)]
+        #[allow(dead_code)]
+        struct Cluster {
+            #[toml_example(nesting, empty_template)]
+            nodes: Vec<Service>,
+        }
+        assert_eq!(
+            toml::from_str::<Cluster>(&Cluster::toml_example()).unwrap(),
+            Cluster::default()
+        );
+    }
+
+    #[test]
+    fn recursive_nesting() {
+        /// Node of a tree
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Node {
+            /// Node.name should be a string
+            name: String,
+            /// Node.child is a node
+            #[toml_example(nesting)]
+            child: Option<Box<Node>>,
+        }
+        assert_eq!(
+            Node::toml_example(),
+            r#"# Node of a tree
+# Node.name should be a string
+name = ""
+
+# Node.child is a node
+# Node of a tree
+# [child]
+# Node.name should be a string
+# name = ""
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Node>(&Node::toml_example()).unwrap(),
+            Node::default()
+        );
+        assert_eq!(
+            toml::from_str::<Node>(&Node::toml_example().replace("# [child]", "[child]").replace("# name", "name"))
+                .unwrap(),
+            Node {
+                child: Some(Box::default()),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn mutual_recursive_nesting() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Dir {
+            /// Dir.name should be a string
+            name: String,
+            /// Dir.link links to a file
+            #[toml_example(nesting)]
+            link: Option<Box<File>>,
+        }
+
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct File {
+            /// File.size should be a number
+            size: usize,
+            /// File.parent is a dir
+            #[toml_example(nesting)]
+            parent: Option<Box<Dir>>,
+        }
+
+        assert_eq!(
+            Dir::toml_example(),
+            r#"# Dir.name should be a string
+name = ""
+
+# Dir.link links to a file
+# [link]
+# File.size should be a number
+# size = 0
+
+# File.parent is a dir
+# [parent]
+# Dir.name should be a string
+# name = ""
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Dir>(&Dir::toml_example()).unwrap(),
+            Dir::default()
+        );
+    }
+
+    #[test]
+    fn recursive_prefix_nesting() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Node {
+            /// Node.name should be a string
+            name: String,
+            /// Node.child is a node
+            #[toml_example(nesting = prefix)]
+            child: Option<Box<Node>>,
+        }
+        assert_eq!(
+            Node::toml_example(),
+            r#"# Node.name should be a string
+name = ""
+
+# Node.child is a node
+# Node.name should be a string
+# child.name = ""
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Node>(&Node::toml_example()).unwrap(),
+            Node::default()
+        );
+    }
+
+    #[test]
+    fn recursive_nesting_of_vec() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Tree {
+            /// Tree.name should be a string
+            name: String,
+            /// Tree.children are trees
+            #[serde(default)]
+            #[toml_example(nesting)]
+            children: Vec<Tree>,
+        }
+        assert_eq!(
+            Tree::toml_example(),
+            r#"# Tree.name should be a string
+name = ""
+
+# Tree.children are trees
+[[children]]
+# Tree.name should be a string
+name = ""
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Tree>(&Tree::toml_example()).unwrap(),
+            Tree {
+                children: vec![Tree::default()],
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn nesting_same_name_in_other_module() {
+        mod legacy {
+            use crate as toml_example;
+            use serde_derive::Deserialize;
+            use alloc::string::String;
+            use toml_example::TomlExample;
+
+            #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+            #[allow(dead_code)]
+            pub struct Config {
+                /// legacy::Config.path should be a string
+                pub path: String,
+            }
+        }
+
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.port should be a number
+            port: usize,
+            /// Config.legacy is not recursive
+            #[toml_example(nesting)]
+            legacy: legacy::Config,
+        }
+
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.port should be a number
+port = 0
+
+# Config.legacy is not recursive
+[legacy]
+# legacy::Config.path should be a string
+path = ""
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config::default()
+        );
+    }
+
+    #[test]
+    fn nesting_by_section() {
+        /// Inner is a config live in Outer
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Inner {
+            /// Inner.a should be a number
+            a: usize,
+        }
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Outer {
+            /// Outer.inner is a complex struct
+            #[toml_example(nesting = section)]
+            inner: Inner,
+        }
+        assert_eq!(
+            Outer::toml_example(),
+            r#"# Outer.inner is a complex struct
+# Inner is a config live in Outer
+[inner]
+# Inner.a should be a number
+a = 0
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Outer>(&Outer::toml_example()).unwrap(),
+            Outer::default()
+        );
+    }
+
+    #[test]
+    fn nesting_by_prefix() {
+        /// Inner is a config live in Outer
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Inner {
+            /// Inner.a should be a number
+            a: usize,
+        }
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Outer {
+            /// Outer.inner is a complex struct
+            #[toml_example(nesting = prefix)]
+            inner: Inner,
+        }
+        assert_eq!(
+            Outer::toml_example(),
+            r#"# Outer.inner is a complex struct
+# Inner is a config live in Outer
+# Inner.a should be a number
+inner.a = 0
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Outer>(&Outer::toml_example()).unwrap(),
+            Outer::default()
+        );
+    }
+
+    #[test]
+    fn nesting_vector() {
+        /// Service with specific port
+        #[derive(TomlExample, Deserialize)]
+        #[allow(dead_code)]
+        struct Service {
+            /// port should be a number
+            port: usize,
+        }
+        #[derive(TomlExample, Deserialize)]
+        #[allow(dead_code)]
+        struct Node {
+            /// Services are running in the node
+            #[toml_example(nesting)]
+            services: Vec<Service>,
+        }
+        assert_eq!(
+            Node::toml_example(),
+            r#"# Services are running in the node
+# Service with specific port
+[[services]]
+# port should be a number
+port = 0
+
+"#
+        );
+        assert!(toml::from_str::<Node>(&Node::toml_example()).is_ok());
+    }
+
+    #[test]
+    fn nesting_hashmap() {
+        /// Service with specific port
+        #[derive(TomlExample, Deserialize)]
+        #[allow(dead_code)]
+        struct Service {
+            /// port should be a number
+            port: usize,
+        }
+        #[derive(TomlExample, Deserialize)]
+        #[allow(dead_code)]
+        struct Node {
+            /// Services are running in the node
+            #[toml_example(nesting)]
+            services: HashMap<String, Service>,
+        }
+        assert_eq!(
+            Node::toml_example(),
+            r#"# Services are running in the node
+# Service with specific port
+[services.example]
+# port should be a number
+port = 0
+
+"#
+        );
+        assert!(toml::from_str::<Node>(&Node::toml_example()).is_ok());
+    }
+
+    #[test]
+    fn optional_nesting() {
+        /// Inner is a config live in Outer
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Inner {
+            /// Inner.a should be a number
+            a: usize,
+        }
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Outer {
+            /// Outer.inner is a complex struct
+            #[toml_example(nesting)]
+            inner: Option<Inner>,
+        }
+        assert_eq!(
+            Outer::toml_example(),
+            r#"# Outer.inner is a complex struct
+# Inner is a config live in Outer
+# [inner]
+# Inner.a should be a number
+# a = 0
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Outer>(&Outer::toml_example()).unwrap(),
+            Outer::default()
+        );
+    }
+
+    #[test]
+    fn optional_nesting_by_section() {
+        /// Inner is a config live in Outer
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Inner {
+            /// Inner.a should be a number
+            a: usize,
+        }
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Outer {
+            /// Outer.inner is a complex struct
+            #[toml_example(nesting = section)]
+            inner: Option<Inner>,
+        }
+        assert_eq!(
+            Outer::toml_example(),
+            r#"# Outer.inner is a complex struct
+# Inner is a config live in Outer
+# [inner]
+# Inner.a should be a number
+# a = 0
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Outer>(&Outer::toml_example()).unwrap(),
+            Outer::default()
+        );
+    }
+
+    #[test]
+    fn optional_nesting_by_prefix() {
+        /// Inner is a config live in Outer
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Inner {
+            /// Inner.a should be a number
+            a: usize,
+        }
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Outer {
+            /// Outer.inner is a complex struct
+            #[toml_example(nesting = prefix)]
+            inner: Option<Inner>,
+        }
+        assert_eq!(
+            Outer::toml_example(),
+            r#"# Outer.inner is a complex struct
+# Inner is a config live in Outer
+# Inner.a should be a number
+# inner.a = 0
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Outer>(&Outer::toml_example()).unwrap(),
+            Outer::default()
+        );
+    }
+
+    #[test]
+    fn optional_nesting_vector() {
+        /// Service with specific port
+        #[derive(TomlExample, Deserialize)]
+        #[allow(dead_code)]
+        struct Service {
+            /// port should be a number
+            port: usize,
+        }
+        #[derive(TomlExample, Deserialize)]
+        #[allow(dead_code)]
+        struct Node {
+            /// Services are running in the node
+            #[toml_example(nesting)]
+            services: Option<Vec<Service>>,
+        }
+        assert_eq!(
+            Node::toml_example(),
+            r#"# Services are running in the node
+# Service with specific port
+# [[services]]
+# port should be a number
+# port = 0
+
+"#
+        );
+        assert!(toml::from_str::<Node>(&Node::toml_example()).is_ok());
+    }
+
+    #[test]
+    fn optional_nesting_hashmap() {
+        /// Service with specific port
+        #[derive(TomlExample, Deserialize)]
+        #[allow(dead_code)]
+        struct Service {
+            /// port should be a number
+            port: usize,
+        }
+        #[derive(TomlExample, Deserialize)]
+        #[allow(dead_code)]
+        struct Node {
+            /// Services are running in the node
+            #[toml_example(nesting)]
+            services: Option<HashMap<String, Service>>,
+        }
+        assert_eq!(
+            Node::toml_example(),
+            r#"# Services are running in the node
+# Service with specific port
+# [services.example]
+# port should be a number
+# port = 0
+
+"#
+        );
+        assert!(toml::from_str::<Node>(&Node::toml_example()).is_ok());
+    }
+
+    #[test]
+    fn optional_nesting_hashmap_of_vec() {
+        use std::collections::BTreeMap;
+
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug, Clone)]
+        #[allow(dead_code)]
+        struct Service {
+            /// port should be a number
+            #[toml_example(default = 80)]
+            port: usize,
+        }
+
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Node {
+            /// Services are grouped by name
+            #[toml_example(nesting)]
+            services: Option<HashMap<String, Vec<Service>>>,
+            /// Backups are grouped by name
+            #[toml_example(nesting)]
+            #[toml_example(default = daily)]
+            backups: BTreeMap<String, Vec<Service>>,
+        }
+
+        let example = Node::toml_example();
+        assert_eq!(
+            example,
+            r#"# Services are grouped by name
+# [[services.example]]
+# port should be a number
+# port = 80
+
+# Backups are grouped by name
+[[backups.daily]]
+# port should be a number
+port = 80
+
+"#
+        );
+        let backups = BTreeMap::from([("daily".to_string(), vec![Service { port: 80 }])]);
+        assert_eq!(
+            toml::from_str::<Node>(&example).unwrap(),
+            Node {
+                services: None,
+                backups: backups.clone(),
+            }
+        );
+        assert_eq!(
+            toml::from_str::<Node>(&example.replace("# [[", "[[").replace("# port =", "port ="))
+                .unwrap(),
+            Node {
+                services: Some(HashMap::from([(
+                    "example".to_string(),
+                    vec![Service { port: 80 }]
+                )])),
+                backups,
+            }
+        );
+    }
+
+    #[test]
+    fn nesting_hashmap_with_enum_key() {
+        #[derive(Deserialize, PartialEq, Eq, Hash, Debug)]
+        #[serde(rename_all = "snake_case")]
+        #[allow(dead_code)]
+        enum Region {
+            UsEast,
+            EuWest,
+        }
+
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Service {
+            /// port should be a number
+            #[toml_example(default = 80)]
+            port: usize,
+        }
+
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Node {
+            /// Services are running in the region
+            #[toml_example(nesting)]
+            #[toml_example(default = us_east)]
+            services: HashMap<Region, Service>,
+            /// Aliases of the region
+            #[toml_example(nesting)]
+            #[toml_example(default = "us:east")]
+            aliases: HashMap<String, Service>,
+        }
+
+        assert_eq!(
+            Node::toml_example(),
+            r#"# Services are running in the region
+[services.us_east]
+# port should be a number
+port = 80
+
+# Aliases of the region
+[aliases."us:east"]
+# port should be a number
+port = 80
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Node>(&Node::toml_example()).unwrap(),
+            Node {
+                services: HashMap::from([(Region::UsEast, Service { port: 80 })]),
+                aliases: HashMap::from([("us:east".to_string(), Service { port: 80 })]),
+            }
+        );
+
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Backup {
+            /// Backups are in the regions
+            #[toml_example(nesting)]
+            backups: HashMap<Region, Service>,
+        }
+        assert_eq!(
+            Backup::toml_example(),
+            r#"# Backups are in the regions
+[backups.example]
+# WARNING: the key `example` may not deserialize into `Region`
+# port should be a number
+port = 80
+
+"#
+        );
+    }
+
+    #[test]
+    fn hashmap_keys() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.limits is a map of numbers
+            limits: HashMap<String, usize>,
+            /// Config.weights is a map with given keys
+            #[toml_example(default = [a, b, c])]
+            weights: HashMap<String, usize>,
+            /// Config.labels is optional
+            labels: Option<HashMap<String, String>>,
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.limits is a map of numbers
+limits = { example = 0 }
+
+# Config.weights is a map with given keys
+weights = { a = 0, b = 0, c = 0 }
+
+# Config.labels is optional
+# labels = { example = "" }
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config {
+                limits: HashMap::from([("example".to_string(), 0)]),
+                weights: HashMap::from([
+                    ("a".to_string(), 0),
+                    ("b".to_string(), 0),
+                    ("c".to_string(), 0)
+                ]),
+                labels: None,
+            }
+        );
+    }
+
+    #[test]
+    fn nesting_vec_of_hashmap() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Service {
+            /// port should be a number
+            #[toml_example(default = 80)]
+            port: usize,
+        }
+
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Node {
+            /// Each group is a map of services
+            #[toml_example(nesting)]
+            #[toml_example(default = http)]
+            groups: Vec<HashMap<String, Service>>,
+            /// Optional groups
+            #[toml_example(nesting)]
+            optional_groups: Option<Vec<HashMap<String, Service>>>,
+        }
+
+        let example = Node::toml_example();
+        assert_eq!(
+            example,
+            r#"# Each group is a map of services
+[[groups]]
+[groups.http]
+# port should be a number
+port = 80
+
+# Optional groups
+# [[optional_groups]]
+# [optional_groups.example]
+# port should be a number
+# port = 80
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Node>(&example).unwrap(),
+            Node {
+                groups: vec![HashMap::from([("http".to_string(), Service { port: 80 })])],
+                optional_groups: None,
+            }
+        );
+        assert_eq!(
+            toml::from_str::<Node>(&example.replace("# [", "[").replace("# port =", "port ="))
+                .unwrap()
+                .optional_groups,
+            Some(vec![HashMap::from([(
+                "example".to_string(),
+                Service { port: 80 }
+            )])])
+        );
+    }
+
+    #[test]
+    fn vec_of_hashmap_without_nesting() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.m is an array of inline tables
+            m: Vec<HashMap<String, usize>>,
+        }
+
+        let example = Config::toml_example();
+        assert_eq!(
+            example,
+            r#"# Config.m is an array of inline tables
+m = [ { example = 0 }, ]
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&example).unwrap().m,
+            vec![HashMap::from([("example".to_string(), 0)])]
+        );
+    }
+
+    #[test]
+    fn nesting_hashmap_with_default_name() {
+        /// Service with specific port
+        #[derive(TomlExample, Deserialize)]
+        #[allow(dead_code)]
+        struct Service {
+            /// port should be a number
+            #[toml_example(default = 80)]
+            port: usize,
+        }
+        #[derive(TomlExample, Deserialize)]
+        #[allow(dead_code)]
+        struct Node {
+            /// Services are running in the node
+            #[toml_example(nesting)]
+            #[toml_example(default = http)]
+            services: HashMap<String, Service>,
+        }
+        assert_eq!(
+            Node::toml_example(),
+            r#"# Services are running in the node
+# Service with specific port
+[services.http]
+# port should be a number
+port = 80
+
+"#
+        );
+        assert!(toml::from_str::<Node>(&Node::toml_example()).is_ok());
+    }
+
+    #[test]
+    fn nesting_hashmap_with_dash_name() {
+        /// Service with specific port
+        #[derive(TomlExample, Deserialize)]
+        #[allow(dead_code)]
+        struct Service {
+            /// port should be a number
+            #[toml_example(default = 80)]
+            port: usize,
+        }
+        #[derive(TomlExample, Deserialize)]
+        #[allow(dead_code)]
+        struct Node {
+            /// Services are running in the node
+            #[toml_example(nesting, dash_key)]
+            #[toml_example(default = http.01)]
+            services: HashMap<String, Service>,
+        }
+        assert_eq!(
+            Node::toml_example(),
+            r#"# Services are running in the node
+# Service with specific port
+[services.http-01]
+# port should be a number
+port = 80
+
+"#
+        );
+        assert!(toml::from_str::<Node>(&Node::toml_example()).is_ok());
+    }
+
+    #[test]
+    fn hashmap_of_struct_inline() {
+        use std::collections::BTreeMap;
+
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Service {
+            /// port should be a number
+            port: usize,
+        }
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Node {
+            /// Services are running in the node
+            #[toml_example(nesting = inline)]
+            services: HashMap<String, Service>,
+            /// Backups are optional
+            #[toml_example(nesting = inline)]
+            backups: Option<BTreeMap<String, Service>>,
+        }
+        assert_eq!(
+            Node::toml_example(),
+            r#"# Services are running in the node
+services = { example = { port = 0 } }
+
+# Backups are optional
+# backups = { example = { port = 0 } }
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Node>(&Node::toml_example()).unwrap(),
+            Node {
+                services: HashMap::from([("example".to_string(), Service::default())]),
+                backups: None,
+            }
+        );
+    }
+
+    #[test]
+    fn hashmap_of_non_toml_example_value() {
+        #[derive(Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        enum Mode {
+            #[default]
+            Active,
+            Passive,
+        }
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Node {
+            /// Modes of the services
+            modes: HashMap<String, Mode>,
+        }
+        assert_eq!(
+            Node::toml_example(),
+            r#"# Modes of the services
+modes = { example = "" }
+
+"#
+        );
+    }
+
+    #[test]
+    fn nesting_hashmap_with_quoted_name() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Service {
+            /// port should be a number
+            #[toml_example(default = 80)]
+            port: usize,
+        }
+        #[derive(TomlExample, Deserialize, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Node {
+            #[toml_example(nesting, default = "my key")]
+            services: HashMap<String, Service>,
+        }
+        assert_eq!(
+            Node::toml_example(),
+            r#"[services."my key"]
+# port should be a number
+port = 80
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Node>(&Node::toml_example()).unwrap(),
+            Node {
+                services: HashMap::from([("my key".to_string(), Service { port: 80 })]),
+            }
+        );
+    }
+
+    #[test]
+    fn nesting_hashmap_with_dotted_name() {
+        #[derive(TomlExample, Deserialize, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Service {
+            #[toml_example(default = 80)]
+            port: usize,
+        }
+        #[derive(TomlExample, Deserialize, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Node {
+            /// the quoted key keeps the dot
+            #[toml_example(nesting, default = "http.01")]
+            services: HashMap<String, Service>,
+            /// the unquoted key also keeps the dot
+            #[toml_example(nesting, default = http.01)]
+            backups: HashMap<String, Service>,
+            /// the dot is replaced by a dash with dash_key
+            #[toml_example(nesting, dash_key, default = http.01)]
+            mirrors: HashMap<String, Service>,
+            /// the quoted key is escaped in toml
+            #[toml_example(nesting, default = "esc\u{1b}")]
+            escaped: HashMap<String, Service>,
+        }
+        assert_eq!(
+            Node::toml_example(),
+            r#"# the quoted key keeps the dot
+[services."http.01"]
+port = 80
+
+# the unquoted key also keeps the dot
+[backups."http.01"]
+port = 80
+
+# the dot is replaced by a dash with dash_key
+[mirrors.http-01]
+port = 80
+
+# the quoted key is escaped in toml
+[escaped."esc\u001B"]
+port = 80
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Node>(&Node::toml_example()).unwrap(),
+            Node {
+                services: HashMap::from([("http.01".to_string(), Service { port: 80 })]),
+                backups: HashMap::from([("http.01".to_string(), Service { port: 80 })]),
+                mirrors: HashMap::from([("http-01".to_string(), Service { port: 80 })]),
+                escaped: HashMap::from([("esc\u{1b}".to_string(), Service { port: 80 })]),
+            }
+        );
+    }
+
+    #[test]
+    fn enum_variants_as_sections() {
+        /// Storage backend
+        #[derive(TomlExample, Deserialize, PartialEq, Debug)]
+        #[toml_example(enum_variants_as_sections)]
+        #[serde(rename_all = "snake_case")]
+        enum Backend {
+            /// Store in a file
+            File {
+                /// path of the file
+                path: String,
+            },
+            /// Store in a database
+            DataBase { url: String, pool: usize },
+        }
+        #[derive(TomlExample, Deserialize, PartialEq, Debug)]
+        struct Config {
+            #[toml_example(nesting)]
+            backend: Backend,
+        }
+        assert_eq!(
+            Backend::toml_example(),
+            r#"# Storage backend
+# Store in a file
+# [file]
+# path of the file
+# path = ""
+
+# Store in a database
+# [data_base]
+# url = ""
+
+# pool = 0
+
+"#
+        );
+        let example = Config::toml_example();
+        assert_eq!(
+            example,
+            r#"# Storage backend
+# Store in a file
+# [backend.file]
+# path of the file
+# path = ""
+
+# Store in a database
+# [backend.data_base]
+# url = ""
+
+# pool = 0
+
+"#
+        );
+        let uncommented = example
+            .replace("# [backend.file]", "[backend.file]")
+            .replace("# path =", "path =");
+        assert_eq!(
+            toml::from_str::<Config>(&uncommented).unwrap(),
+            Config {
+                backend: Backend::File { path: "".into() }
+            }
+        );
+    }
+
+    #[test]
+    fn adjacently_tagged_enum() {
+        #[derive(TomlExample, Deserialize, PartialEq, Debug)]
+        #[serde(tag = "type", content = "content")]
+        #[allow(dead_code)]
+        enum Backend {
+            File {
+                path: String,
+            },
+            #[toml_example(default)]
+            Database {
+                url: String,
+                pool: usize,
+            },
+            Memory,
+        }
+        #[derive(TomlExample, Deserialize, PartialEq, Debug)]
+        struct Config {
+            /// Config.backend is the storage backend
+            #[toml_example(nesting)]
+            backend: Backend,
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.backend is the storage backend
+[backend]
+type = "Database"
+content = { url = "", pool = 0 }
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config {
+                backend: Backend::Database {
+                    url: "".into(),
+                    pool: 0
+                }
+            }
+        );
+    }
+
+    #[test]
+    fn is_enum() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[serde(rename_all = "lowercase")]
+        #[allow(dead_code)]
+        enum Level {
+            #[default]
+            Info,
+            Debug,
+        }
+
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        enum Mode {
+            Fast,
+            #[default]
+            #[serde(rename = "safe-mode")]
+            Safe,
+        }
+
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.level is the log level
+            #[serde(default)]
+            #[toml_example(enum)]
+            level: Level,
+            /// Config.mode is the default variant of Mode
+            #[toml_example(enum)]
+            mode: Mode,
+            /// Config.fallback is optional
+            #[toml_example(enum)]
+            fallback: Option<Mode>,
+            /// Config.debug is given
+            #[toml_example(enum, default = "debug")]
+            debug: Level,
+        }
+
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.level is the log level
+level = "info"
+
+# Config.mode is the default variant of Mode
+mode = "safe-mode"
+
+# Config.fallback is optional
+# fallback = "safe-mode"
+
+# Config.debug is given
+debug = "debug"
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config {
+                debug: Level::Debug,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn enum_first() {
+        #[derive(Deserialize, PartialEq, Debug)]
+        #[allow(dead_code)]
+        enum Mode {
+            Fast,
+            Safe,
+        }
+
+        #[derive(TomlExample, Deserialize, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.mode has no default variant
+            #[toml_example(enum, first = "Safe")]
+            mode: Mode,
+            /// Config.fallback is optional
+            #[toml_example(enum, first = Fast)]
+            fallback: Option<Mode>,
+        }
+
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.mode has no default variant
+mode = "Safe"
+
+# Config.fallback is optional
+# fallback = "Fast"
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config {
+                mode: Mode::Safe,
+                fallback: None,
+            }
+        );
+    }
+
+    #[test]
+    fn none_default() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.a is absent by default
+            #[toml_example(default = None)]
+            a: Option<usize>,
+            /// Config.b is absent even if it is required
+            #[toml_example(require, default = None)]
+            b: Option<String>,
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.a is absent by default
+# a = 0
+
+# Config.b is absent even if it is required
+# b = ""
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config::default()
+        );
+    }
+
+    #[test]
+    fn raw_string_default() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.path is a windows path
+            #[toml_example(default = r"C:\Program Files\app")]
+            path: String,
+            /// Config.pattern is a regex
+            #[toml_example(default = r#"it's "\d+""#)]
+            pattern: String,
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.path is a windows path
+path = 'C:\Program Files\app'
+
+# Config.pattern is a regex
+pattern = "it's \"\\d+\""
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config {
+                path: r"C:\Program Files\app".into(),
+                pattern: r#"it's "\d+""#.into(),
+            }
+        );
+    }
+
+    #[test]
+    fn enum_as_int() {
+        #[derive(TomlExample, Default, PartialEq, Debug)]
+        #[toml_example(enum_as_int)]
+        #[allow(dead_code)]
+        enum Level {
+            Low = 1,
+            #[default]
+            High = 10,
+        }
+
+        fn level_from_int<'de, D: serde::Deserializer<'de>>(d: D) -> Result<Level, D::Error> {
+            match <u8 as serde::Deserialize>::deserialize(d)? {
+                1 => Ok(Level::Low),
+                10 => Ok(Level::High),
+                _ => Err(serde::de::Error::custom("unknown level")),
+            }
+        }
+
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.level is 1 for low, 10 for high
+            #[serde(deserialize_with = "level_from_int")]
+            #[toml_example(enum)]
+            level: Level,
+        }
+
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.level is 1 for low, 10 for high
+level = 10
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config::default()
+        );
+    }
+
+    #[test]
+    fn type_hints() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[toml_example(type_hints)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.a is a number
+            a: usize,
+            /// Config.b is a string
+            b: String,
+            c: Option<f64>,
+            d: Vec<String>,
+            e: bool,
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.a is a number
+# (integer)
+a = 0
+
+# Config.b is a string
+# (string)
+b = ""
+
+# (float)
+# c = 0.0
+
+# (array of strings)
+d = [ "", ]
+
+# (boolean)
+e = false
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config {
+                d: vec!["".into()],
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn doc_starts_with_sharp() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            #[doc = "# Config.a is a heading"]
+            /// Config.a is optional
+            a: Option<usize>,
+            #[doc = "## Config.b is a heading"]
+            b: usize,
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.a is a heading
+# Config.a is optional
+# a = 0
+
+# Config.b is a heading
+b = 0
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config::default()
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn example_file() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.a should be a number
+            a: usize,
+        }
+        let mut tmp_file = std::env::temp_dir();
+        tmp_file.push("example_file_config.toml");
+        Config::to_toml_example_file(&tmp_file).unwrap();
+        tmp_file.set_file_name("example_file_config.toml.example");
+        assert_eq!(
+            std::fs::read_to_string(tmp_file).unwrap(),
+            r#"# Config.a should be a number
+a = 0
+
+"#
+        );
+    }
+
+    #[test]
+    fn align() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Inner {
+            a: usize,
+        }
+
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[toml_example(align, width = 20)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.a is short
+            a: usize,
+            /// Config.max_connections is long
+            max_connections: usize,
+            /// Config.timeout is optional, the commented key is aligned
+            timeout: Option<usize>,
+            /// Config.hosts is wrapped
+            #[toml_example(default = ["alpha", "beta"])]
+            hosts: Vec<String>,
+            /// Config.inner_section is not aligned
+            #[toml_example(nesting)]
+            inner_section: Inner,
+        }
+
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.a is short
+a               = 0
+
+# Config.max_connections is long
+max_connections = 0
+
+# Config.timeout is optional, the commented key is aligned
+# timeout       = 0
+
+# Config.hosts is wrapped
+hosts           = [
+    "alpha",
+    "beta",
+]
+
+# Config.inner_section is not aligned
+[inner_section]
+a = 0
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config {
+                hosts: vec!["alpha".into(), "beta".into()],
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn indent_tabs() {
+        /// Service with specific port
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Service {
+            /// port should be a number
+            #[toml_example(default = 80)]
+            port: usize,
+            /// hosts of the service
+            #[toml_example(default = ["a"])]
+            hosts: Vec<String>,
+        }
+
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[toml_example(indent_tabs)]
+        #[allow(dead_code)]
+        struct Node {
+            /// Node.name should be a string
+            name: String,
+            /// Services are running in the node
+            #[toml_example(nesting, default = http)]
+            services: HashMap<String, Service>,
+        }
+
+        assert_eq!(
+            Node::toml_example(),
+            "# Node.name should be a string
+name = \"\"
+
+# Services are running in the node
+# Service with specific port
+[services.http]
+\t# port should be a number
+\tport = 80
+
+\t# hosts of the service
+\thosts = [ \"a\", ]
+
+"
+        );
+        assert_eq!(
+            toml::from_str::<Node>(&Node::toml_example()).unwrap(),
+            Node {
+                name: String::new(),
+                services: HashMap::from([(
+                    "http".to_string(),
+                    Service {
+                        port: 80,
+                        hosts: vec!["a".into()],
+                    }
+                )]),
+            }
+        );
+    }
+
+    #[test]
+    fn width() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[toml_example(width = 26)]
+        #[allow(dead_code)]
+        struct Inner {
+            /// Inner.ports fits the width
+            #[toml_example(default = [80, 443, 8080])]
+            ports: Vec<usize>,
+            /// Inner.backup_ports is wider than the width
+            #[toml_example(default = [80, 443, 8080])]
+            backup_ports: Vec<usize>,
+        }
+
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.hosts wraps with the field width
+            #[toml_example(width = 10, default = ["a", "b"])]
+            hosts: Option<Vec<String>>,
+            #[toml_example(nesting)]
+            inner: Inner,
+            #[toml_example(nesting)]
+            optional_inner: Option<Inner>,
+        }
+
+        let example = Config::toml_example();
+        assert_eq!(
+            example,
+            r#"# Config.hosts wraps with the field width
+# hosts = [
+#     "a",
+#     "b",
+# ]
+
+[inner]
+# Inner.ports fits the width
+ports = [ 80, 443, 8080, ]
+
+# Inner.backup_ports is wider than the width
+backup_ports = [
+    80,
+    443,
+    8080,
+]
+
+# [optional_inner]
+# Inner.ports fits the width
+# ports = [ 80, 443, 8080, ]
+
+# Inner.backup_ports is wider than the width
+# backup_ports = [
+#     80,
+#     443,
+#     8080,
+# ]
+
+"#
+        );
+        let inner = Inner {
+            ports: vec![80, 443, 8080],
+            backup_ports: vec![80, 443, 8080],
+        };
+        assert_eq!(
+            toml::from_str::<Config>(&example).unwrap(),
+            Config {
+                inner,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn header() {
+        /// Config is a struct
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.a should be a number
+            a: usize,
+        }
+        let example = Config::to_toml_example_string_with_header("Auto-generated\n\nversion 1.0");
+        assert_eq!(
+            example,
+            r#"# Auto-generated
+#
+# version 1.0
+
+# Config is a struct
+# Config.a should be a number
+a = 0
+
+"#
+        );
+        assert_eq!(toml::from_str::<Config>(&example).unwrap(), Config::default());
+    }
+
+    #[test]
+    fn struct_default() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[toml_example(default)]
+        #[allow(dead_code)]
+        struct Inner {
+            /// Inner.retries should be a number
+            retries: usize,
+        }
+
+        #[derive(TomlExample, Deserialize, PartialEq, Debug)]
+        #[toml_example(default)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.port should be a number
+            port: usize,
+            /// Config.name should be a string
+            name: String,
+            /// Config.mode has its own default
+            #[toml_example(default = "fast")]
+            mode: String,
+            /// Config.inner is from the default of Config
+            #[toml_example(nesting)]
+            inner: Inner,
+        }
+        impl Default for Config {
+            fn default() -> Self {
+                Self {
+                    port: 8080,
+                    name: "svc".into(),
+                    mode: "safe".into(),
+                    inner: Inner { retries: 3 },
+                }
+            }
+        }
+
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.port should be a number
+port = 8080
+
+# Config.name should be a string
+name = "svc"
+
+# Config.mode has its own default
+mode = "fast"
+
+# Config.inner is from the default of Config
+[inner]
+# Inner.retries should be a number
+retries = 3
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config {
+                mode: "fast".into(),
+                ..Default::default()
+            }
+        );
+        assert_eq!(
+            Inner::toml_example(),
+            r#"# Inner.retries should be a number
+retries = 0
+
+"#
+        );
+    }
+
+    #[test]
+    fn struct_default_enum_and_map() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        enum Mode {
+            #[default]
+            #[serde(rename = "fast")]
+            Fast,
+            #[serde(rename = "safe")]
+            Safe,
+        }
+
+        #[derive(TomlExample, Deserialize, PartialEq, Debug)]
+        #[toml_example(default)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.mode is the default variant of Mode
+            #[toml_example(enum)]
+            mode: Mode,
+            /// Config.limits is from the default of Config
+            limits: HashMap<String, usize>,
+            /// Config.ratio is not a number
+            ratio: f64,
+            /// Config.sep has a control character
+            sep: String,
+        }
+        impl Default for Config {
+            fn default() -> Self {
+                Self {
+                    mode: Mode::Fast,
+                    limits: HashMap::from([("cpu".to_string(), 2)]),
+                    ratio: f64::NAN,
+                    sep: "\t\0".into(),
+                }
+            }
+        }
+
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.mode is the default variant of Mode
+mode = "fast"
+
+# Config.limits is from the default of Config
+limits = { cpu = 2 }
+
+# Config.ratio is not a number
+ratio = nan
+
+# Config.sep has a control character
+sep = "\t\u0000"
+
+"#
+        );
+        let config = toml::from_str::<Config>(&Config::toml_example()).unwrap();
+        assert_eq!(config.limits, Config::default().limits);
+        assert_eq!(config.sep, Config::default().sep);
+        assert!(config.ratio.is_nan());
+    }
+
+    #[test]
+    fn struct_default_precedence() {
+        fn default_name() -> String {
+            "from-fn".into()
+        }
+        fn default_config() -> SerdeFn {
+            SerdeFn {
+                port: 9090,
+                name: "from-path".into(),
+            }
+        }
+
+        // the struct `#[serde(default)]` is not inherited, the field default is rendered
+        #[derive(TomlExample, Deserialize, PartialEq, Debug)]
+        #[serde(default)]
+        #[allow(dead_code)]
+        struct SerdeDefault {
+            port: usize,
+            #[toml_example(default = "from-field")]
+            name: String,
+            #[serde(default)]
+            retries: usize,
+        }
+        impl Default for SerdeDefault {
+            fn default() -> Self {
+                Self {
+                    port: 8080,
+                    name: "from-struct".into(),
+                    retries: 3,
+                }
+            }
+        }
+        assert_eq!(
+            SerdeDefault::toml_example(),
+            r#"port = 0
+
+name = "from-field"
+
+retries = 0
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<SerdeDefault>(&SerdeDefault::toml_example()).unwrap(),
+            SerdeDefault {
+                port: 0,
+                name: "from-field".into(),
+                retries: 0,
+            }
+        );
+
+        // the field of the struct `#[serde(default)]` does not need `Debug`
+        #[derive(Deserialize, Default)]
+        #[allow(dead_code)]
+        struct Name(String);
+        #[derive(TomlExample, Deserialize, Default)]
+        #[serde(default)]
+        #[allow(dead_code)]
+        struct NotDebug {
+            name: Name,
+        }
+        assert_eq!(
+            NotDebug::toml_example(),
+            r#"name = ""
+
+"#
+        );
+        assert!(toml::from_str::<NotDebug>(&NotDebug::toml_example()).is_ok());
+
+        // the field `#[serde(default = "fn")]` overrides the struct `#[toml_example(default)]`
+        #[derive(TomlExample, Deserialize, PartialEq, Debug)]
+        #[toml_example(default)]
+        #[allow(dead_code)]
+        struct TomlExampleDefault {
+            port: usize,
+            #[serde(default = "default_name")]
+            name: String,
+        }
+        impl Default for TomlExampleDefault {
+            fn default() -> Self {
+                Self {
+                    port: 8080,
+                    name: "from-struct".into(),
+                }
+            }
+        }
+        assert_eq!(
+            TomlExampleDefault::toml_example(),
+            r#"port = 8080
+
+name = "from-fn"
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<TomlExampleDefault>(&TomlExampleDefault::toml_example()).unwrap(),
+            TomlExampleDefault {
+                port: 8080,
+                name: default_name(),
+            }
+        );
+
+        // the struct `#[serde(default = "path")]` is the default instance of `#[toml_example(default)]`
+        #[derive(TomlExample, Deserialize, PartialEq, Debug)]
+        #[serde(default = "default_config")]
+        #[toml_example(default)]
+        #[allow(dead_code)]
+        struct SerdeFn {
+            port: usize,
+            #[toml_example(default = "from-field")]
+            name: String,
+        }
+        assert_eq!(
+            SerdeFn::toml_example(),
+            r#"port = 9090
+
+name = "from-field"
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<SerdeFn>(&SerdeFn::toml_example()).unwrap(),
+            SerdeFn {
+                port: 9090,
+                name: "from-field".into(),
+            }
+        );
+    }
+
+    #[test]
+    fn audit() {
+        #[derive(Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Custom(usize);
+
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[toml_example(audit)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.a should be a number
+            a: usize,
+            /// Config.b is a custom type
+            b: Custom,
+            /// Config.c is a custom type with default
+            #[toml_example(default = 7)]
+            c: Custom,
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.a should be a number
+a = 0
+
+# Config.b is a custom type
+b = ""
+# WARNING: placeholder may be invalid
+
+# Config.c is a custom type with default
+c = 7
+
+"#
+        );
+        assert!(toml::from_str::<Config>(&Config::toml_example()).is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn line_ending() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.a should be a number
+            a: usize,
+        }
+        let mut tmp_file = std::env::temp_dir();
+        tmp_file.push("line_ending_config.toml");
+        Config::to_toml_example_with_line_ending(
+            tmp_file.as_path().to_str().unwrap(),
+            crate::LineEnding::CrLf,
+        )
+        .unwrap();
+        let example = std::fs::read_to_string(tmp_file).unwrap();
+        assert_eq!(example, "# Config.a should be a number\r\na = 0\r\n\r\n");
+        assert_eq!(toml::from_str::<Config>(&example).unwrap(), Config::default());
+        assert_eq!(
+            crate::LineEnding::default().apply(&Config::toml_example()),
+            Config::toml_example()
+        );
+    }
+
+    #[test]
+    fn base64() {
+        fn from_base64<'de, D: serde::Deserializer<'de>>(d: D) -> Result<Vec<u8>, D::Error> {
+            // the empty string is the only base64 value in the example
+            match <String as serde::Deserialize>::deserialize(d)?.as_str() {
+                "" => Ok(Vec::new()),
+                _ => Err(serde::de::Error::custom("not in the example")),
+            }
+        }
+
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[toml_example(type_hints)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.key is the secret key
+            #[serde(deserialize_with = "from_base64")]
+            #[toml_example(base64)]
+            key: Vec<u8>,
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.key is the secret key
+# (string)
+key = ""
+# base64 encoded
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config::default()
+        );
+    }
+
+    #[test]
+    fn duration_secs() {
+        use std::time::Duration;
+
+        fn from_secs<'de, D: serde::Deserializer<'de>>(d: D) -> Result<Duration, D::Error> {
+            <u64 as serde::Deserialize>::deserialize(d).map(Duration::from_secs)
+        }
+        fn default_timeout() -> Duration {
+            Duration::from_secs(30)
+        }
+
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[toml_example(type_hints)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.interval is in seconds
+            #[serde(deserialize_with = "from_secs")]
+            #[toml_example(duration_secs)]
+            interval: Duration,
+            /// Config.timeout is in seconds
+            #[serde(default = "default_timeout", deserialize_with = "from_secs")]
+            #[toml_example(duration_secs)]
+            timeout: Duration,
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.interval is in seconds
+# (integer)
+interval = 0
+
+# Config.timeout is in seconds
+# (integer)
+timeout = 30
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config {
+                interval: Duration::ZERO,
+                timeout: default_timeout(),
+            }
+        );
+    }
+
+    #[test]
+    fn deserialize_with() {
+        fn parse_size<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+            let s: String = serde::Deserialize::deserialize(deserializer)?;
+            s.trim_end_matches("MB")
+                .parse::<u64>()
+                .map(|n| n * 1024 * 1024)
+                .map_err(serde::de::Error::custom)
+        }
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.size is the size of the cache
+            #[serde(deserialize_with = "parse_size")]
+            #[toml_example(default = "10MB")]
+            size: u64,
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.size is the size of the cache
+size = "10MB"
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config { size: 10 * 1024 * 1024 }
+        );
+    }
+
+    #[test]
+    fn as_string() {
+        /// A stand-in of `semver::Version`
+        #[derive(PartialEq, Debug)]
+        struct Version(u64, u64, u64);
+        impl Default for Version {
+            fn default() -> Self {
+                Version(0, 1, 0)
+            }
+        }
+        impl std::str::FromStr for Version {
+            type Err = String;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                let parts: Vec<u64> = s
+                    .split('.')
+                    .map(|p| p.parse().map_err(|_| s.to_string()))
+                    .collect::<Result<_, _>>()?;
+                match parts[..] {
+                    [major, minor, patch] => Ok(Version(major, minor, patch)),
+                    _ => Err(s.to_string()),
+                }
+            }
+        }
+        impl std::fmt::Display for Version {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}.{}.{}", self.0, self.1, self.2)
+            }
+        }
+        fn from_str<'de, D: serde::Deserializer<'de>>(d: D) -> Result<Version, D::Error> {
+            <String as serde::Deserialize>::deserialize(d)?
+                .parse()
+                .map_err(serde::de::Error::custom)
+        }
+
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[toml_example(type_hints)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.version is parsed with FromStr
+            #[serde(deserialize_with = "from_str")]
+            #[toml_example(as_string, default = "1.2.3")]
+            version: Version,
+            /// Config.minimum is the Default of Version
+            #[serde(deserialize_with = "from_str")]
+            #[toml_example(as_string, default)]
+            minimum: Version,
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.version is parsed with FromStr
+# (string)
+version = "1.2.3"
+
+# Config.minimum is the Default of Version
+# (string)
+minimum = "0.1.0"
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config {
+                version: Version(1, 2, 3),
+                minimum: Version::default(),
+            }
+        );
+    }
+
+    #[test]
+    fn keys() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Inner {
+            a: usize,
+        }
+
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Extra {
+            extra_key: usize,
+        }
+
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[serde(rename_all = "kebab-case")]
+        #[allow(dead_code)]
+        struct Config {
+            max_conn: usize,
+            #[serde(rename = "name")]
+            label: Option<String>,
+            #[toml_example(nesting)]
+            inner: Inner,
+            #[toml_example(nesting, section_name = "svc")]
+            #[serde(alias = "svc")]
+            service: Inner,
+            #[toml_example(skip)]
+            skipped: usize,
+            #[serde(flatten)]
+            extra: Extra,
+        }
+        assert_eq!(
+            Config::toml_example_keys(),
+            vec!["max-conn", "name", "inner", "svc", "extra_key"]
+        );
+        assert!(toml::from_str::<toml::Table>(&Config::toml_example())
+            .unwrap()
+            .keys()
+            .all(|k| Config::toml_example_keys().contains(k)));
+    }
+
+    #[test]
+    fn keys_of_flattened_struct_named_value() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Tls {
+            cert: String,
+        }
+
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct KeyValue {
+            port: usize,
+            #[toml_example(nesting)]
+            tls: Tls,
+        }
+
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            a: usize,
+            #[serde(flatten)]
+            kv: KeyValue,
+        }
+        assert_eq!(Config::toml_example_keys(), vec!["a", "port", "tls"]);
+        const { assert!(<Config as TomlExample>::HAS_SECTIONS) };
+    }
+
+    #[test]
+    fn hashmap_default_fn() {
+        fn make_map() -> HashMap<String, String> {
+            HashMap::from([
+                ("b".to_string(), "y".to_string()),
+                ("a key".to_string(), "x".to_string()),
+            ])
+        }
+
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.labels is from a default function
+            #[serde(default = "make_map")]
+            labels: HashMap<String, String>,
+            /// Config.limits is from Default
+            #[serde(default)]
+            limits: HashMap<String, usize>,
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.labels is from a default function
+labels = { "a key" = "x", b = "y" }
+
+# Config.limits is from Default
+limits = {}
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config {
+                labels: make_map(),
+                limits: HashMap::new(),
+            }
+        );
+    }
+
+    #[test]
+    fn element() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.hosts is a list of host
+            #[toml_example(element = "localhost")]
+            hosts: Vec<String>,
+            /// Config.ports is a list of port
+            #[toml_example(element = 80)]
+            ports: Option<Vec<usize>>,
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.hosts is a list of host
+hosts = [ "localhost", ]
+
+# Config.ports is a list of port
+# ports = [ 80, ]
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config {
+                hosts: vec!["localhost".into()],
+                ports: None,
+            }
+        );
+    }
+
+    #[test]
+    fn assoc_const_default() {
+        #[derive(TomlExample, Deserialize, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.port should be a number
+            #[toml_example(default = Self::DEFAULT_PORT)]
+            port: u16,
+            /// Config.host should be a string
+            #[toml_example(default = Self::DEFAULT_HOST)]
+            host: String,
+        }
+        impl Config {
+            const DEFAULT_PORT: u16 = 8080;
+            const DEFAULT_HOST: &'static str = "localhost";
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.port should be a number
+port = 8080
+
+# Config.host should be a string
+host = "localhost"
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config {
+                port: Config::DEFAULT_PORT,
+                host: Config::DEFAULT_HOST.into(),
+            }
+        );
+    }
+
+    #[test]
+    fn const_path_default() {
+        #[derive(TomlExample, Deserialize, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.port is the largest port
+            #[toml_example(default = u16::MAX)]
+            port: u16,
+            /// Config.offset is the smallest offset
+            #[toml_example(default = i64::MIN)]
+            offset: i64,
+            #[toml_example(default = std::f64::consts::PI)]
+            ratio: f64,
+            #[toml_example(default = f64::NAN)]
+            missing: f64,
+            #[toml_example(default = f64::NEG_INFINITY)]
+            floor: f64,
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.port is the largest port
+port = 65535
+
+# Config.offset is the smallest offset
+offset = -9223372036854775808
+
+ratio = 3.141592653589793
+
+missing = nan
+
+floor = -inf
+
+"#
+        );
+        let config = toml::from_str::<Config>(&Config::toml_example()).unwrap();
+        assert_eq!(config.port, u16::MAX);
+        assert_eq!(config.offset, i64::MIN);
+        assert_eq!(config.ratio, std::f64::consts::PI);
+        assert!(config.missing.is_nan());
+        assert_eq!(config.floor, f64::NEG_INFINITY);
+    }
+
+    #[test]
+    fn is_enum_manual_default() {
+        #[derive(TomlExample, Deserialize, PartialEq, Debug)]
+        #[allow(dead_code)]
+        enum Mode {
+            Fast,
+            #[toml_example(default)]
+            #[serde(rename = "safe-mode")]
+            Safe,
+        }
+        #[allow(clippy::derivable_impls)]
+        impl Default for Mode {
+            fn default() -> Self {
+                Mode::Safe
+            }
+        }
+
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.mode is the default of the manual impl
+            #[toml_example(enum)]
+            mode: Mode,
+        }
+
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.mode is the default of the manual impl
+mode = "safe-mode"
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config::default()
+        );
+    }
+
+    #[test]
+    fn hashmap_default_fn_nested() {
+        use std::collections::BTreeMap;
+
+        fn make_map() -> HashMap<String, BTreeMap<String, String>> {
+            HashMap::from([(
+                "outer".to_string(),
+                BTreeMap::from([
+                    ("a\tkey".to_string(), "\u{1b}[0m".to_string()),
+                    ("b".to_string(), "q\"t".to_string()),
+                ]),
+            )])
+        }
+
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.styles is a map of maps
+            #[serde(default = "make_map")]
+            styles: HashMap<String, BTreeMap<String, String>>,
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.styles is a map of maps
+styles = { outer = { "a\tkey" = "\u001B[0m", b = "q\"t" } }
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config { styles: make_map() }
+        );
+    }
+
+    #[test]
+    fn const_path_of_variant() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Eq, Hash, Debug)]
+        #[allow(dead_code)]
+        enum Level {
+            #[default]
+            High,
+            Low,
+        }
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Service {
+            port: usize,
+        }
+        #[derive(TomlExample, Deserialize, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.level is the variant of the path
+            #[toml_example(enum, default = Level::Low)]
+            level: Level,
+            /// Config.fallback is not marked as enum
+            #[toml_example(default = Level::Low)]
+            fallback: Level,
+            /// Config.services are keyed by the variant
+            #[toml_example(nesting, default = Level::Low)]
+            services: HashMap<Level, Service>,
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.level is the variant of the path
+level = "Low"
+
+# Config.fallback is not marked as enum
+fallback = "Low"
+
+# Config.services are keyed by the variant
+[services.Low]
+port = 0
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config {
+                level: Level::Low,
+                fallback: Level::Low,
+                services: HashMap::from([(Level::Low, Service::default())]),
+            }
+        );
+    }
+
+    #[test]
+    fn require() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.a is an optional number
+            #[toml_example(require)]
+            a: Option<usize>,
+            /// Config.b is an optional string
+            #[toml_example(require)]
+            b: Option<String>,
+            #[toml_example(require)]
+            #[toml_example(default = "third")]
+            c: Option<String>,
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.a is an optional number
+a = 0
+
+# Config.b is an optional string
+b = ""
+
+c = "third"
+
+"#
+        );
+    }
+
+    #[test]
+    fn require_default() {
+        #[derive(Deserialize, PartialEq)]
+        struct Port(u16);
+        impl Default for Port {
+            fn default() -> Self {
+                Port(8080)
+            }
+        }
+        impl std::fmt::Debug for Port {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(f, "{}", self.0)
+            }
+        }
+
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.port is the port with a custom default
+            #[toml_example(require, default)]
+            port: Option<Port>,
+            /// Config.retries is an optional number
+            #[toml_example(require, default)]
+            retries: Option<usize>,
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.port is the port with a custom default
+port = 8080
+
+# Config.retries is an optional number
+retries = 0
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config {
+                port: Some(Port(8080)),
+                retries: Some(0),
+            }
+        );
+    }
+
+    #[test]
+    fn split_attributes() {
+        /// Service with specific port
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Service {
+            /// port should be a number
+            #[toml_example(default = 80)]
+            port: usize,
+        }
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Node {
+            /// Node.name should be a string
+            #[serde(default)]
+            #[toml_example(default = "node")]
+            #[toml_example(note = "unique in the cluster")]
+            #[toml_example(commented)]
+            name: String,
+            /// Services are running in the node
+            #[toml_example(nesting)]
+            #[toml_example(default = http)]
+            #[toml_example(note = "more services can be added")]
+            services: HashMap<String, Service>,
+        }
+        assert_eq!(
+            Node::toml_example(),
+            r#"# Node.name should be a string
+# name = "node"
+# unique in the cluster
+
+# Services are running in the node
+# Service with specific port
+[services.http]
+# more services can be added
+# port should be a number
+port = 80
+
+"#
+        );
+        let node = toml::from_str::<Node>(&Node::toml_example().replace("# name", "name")).unwrap();
+        assert_eq!(node.name, "node");
+        assert_eq!(node.services["http"], Service { port: 80 });
+    }
+
+    #[test]
+    fn show_rust_type() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Database {
+            url: String,
+        }
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[toml_example(show_rust_type)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.a should be a number
+            a: usize,
+            b: Option<Vec<String>>,
+            c: HashMap<String, [u8; 2]>,
+            d: std::string::String,
+            #[toml_example(nesting)]
+            database: Database,
+        }
+        let example = Config::toml_example();
+        assert_eq!(
+            example,
+            r#"# Config.a should be a number
+# rust type: usize
+a = 0
+
+# rust type: Option<Vec<String>>
+# b = [ "", ]
+
+# rust type: HashMap<String, [u8; 2]>
+c = { example = [ 0, 0, ] }
+
+# rust type: std::string::String
+d = ""
+
+# rust type: Database
+[database]
+url = ""
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&example).unwrap(),
+            Config {
+                c: HashMap::from([("example".to_string(), [0, 0])]),
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn required_with() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// the username of the proxy
+            username: Option<String>,
+            /// the password of the proxy
+            #[toml_example(required_with = "username")]
+            password: Option<String>,
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"# the username of the proxy
+# username = ""
+
+# the password of the proxy
+# required when username is set
+# password = ""
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config::default()
+        );
+    }
+
+    #[test]
+    fn all_required() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Inner {
+            /// Inner.c is an optional number
+            c: Option<usize>,
+        }
+
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[toml_example(all_required)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.a is an optional number
+            a: Option<usize>,
+            /// Config.b is an optional string
+            #[toml_example(default = "b")]
+            b: Option<String>,
+            /// Config.inner is an optional nesting
+            #[toml_example(nesting)]
+            inner: Option<Inner>,
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.a is an optional number
+a = 0
+
+# Config.b is an optional string
+b = "b"
+
+# Config.inner is an optional nesting
+[inner]
+# Inner.c is an optional number
+# c = 0
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config {
+                a: Some(0),
+                b: Some("b".into()),
+                inner: Some(Inner::default()),
+            }
+        );
+    }
+
+    #[test]
+    fn require_string() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.a is an active empty string
+            #[toml_example(require)]
+            a: Option<String>,
+            /// Config.b has a default over the empty placeholder
+            #[toml_example(require, default = "b")]
+            b: Option<String>,
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.a is an active empty string
+a = ""
+
+# Config.b has a default over the empty placeholder
+b = "b"
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config {
+                a: Some(String::new()),
+                b: Some("b".into()),
+            }
+        );
+    }
+
+    #[test]
+    fn require_show_none() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.a is an optional number
+            #[toml_example(require, show_none)]
+            a: Option<usize>,
+            #[toml_example(require, show_none, default = "third")]
+            b: Option<String>,
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.a is an optional number
+a = 0
+# a = <unset>
+
+b = "third"
+# b = <unset>
+
+"#
+        );
+    }
+
+    #[test]
+    fn require_show_none_in_optional_nesting() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Inner {
+            #[toml_example(require, show_none)]
+            x: Option<usize>,
+        }
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            #[toml_example(nesting = prefix)]
+            p: Option<Inner>,
+            #[toml_example(nesting)]
+            s: Option<Inner>,
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"# p.x = 0
+# p.x = <unset>
+
+# [s]
+# x = 0
+# x = <unset>
+
+"#
+        );
+        assert_eq!(toml::from_str::<Config>(&Config::toml_example()).unwrap(), Config::default());
+    }
+
+    #[test]
+    fn note() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.a is a number
+            #[toml_example(note = "a larger number uses more memory")]
+            #[toml_example(note = "restart is needed after changing")]
+            a: usize,
+            /// Config.b is an optional number
+            #[toml_example(note = "b is ignored when a is zero")]
+            b: Option<usize>,
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.a is a number
+a = 0
+# a larger number uses more memory
+# restart is needed after changing
+
+# Config.b is an optional number
+# b = 0
+# b is ignored when a is zero
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config::default()
+        );
+    }
+
+    #[test]
+    fn commented() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.a is a number
+            a: usize,
+            /// Config.port is a number, the default is 8080
+            #[serde(default)]
+            #[toml_example(commented, default = 8080)]
+            port: usize,
+        }
+        let example = Config::toml_example();
+        assert_eq!(
+            example,
+            r#"# Config.a is a number
+a = 0
+
+# Config.port is a number, the default is 8080
+# port = 8080
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&example).unwrap(),
+            Config::default()
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&example.replace("# port", "port")).unwrap(),
+            Config { a: 0, port: 8080 }
+        );
+    }
+
+    #[test]
+    fn enum_variants() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        enum Priority {
+            /// urgent tasks
+            #[default]
+            Important,
+            /// nice to have
+            Trivial,
+        }
+
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Task {
+            /// Task.priority is the priority of the task
+            #[toml_example(enum, variants = [Important = "urgent tasks", Trivial = "nice to have"])]
+            priority: Priority,
+            /// Task.fallback is optional
+            #[toml_example(enum, variants = [Important, Trivial])]
+            fallback: Option<Priority>,
+        }
+
+        assert_eq!(
+            Task::toml_example(),
+            r#"# Task.priority is the priority of the task
+# - Important: urgent tasks
+# - Trivial: nice to have
+priority = "Important"
+
+# Task.fallback is optional
+# - Important
+# - Trivial
+# fallback = "Important"
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Task>(&Task::toml_example()).unwrap(),
+            Task::default()
+        );
+    }
+
+    #[test]
+    fn toggle_enum() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        enum Toggle {
+            #[default]
+            On,
+            Off,
+        }
+
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.cache turns the cache on or off
+            #[toml_example(enum, default, variants = [On = "enable the cache", Off = "disable the cache"])]
+            cache: Toggle,
+            /// Config.debug is off
+            #[toml_example(enum, default = "Off")]
+            debug: Toggle,
+        }
+
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.cache turns the cache on or off
+# - On: enable the cache
+# - Off: disable the cache
+cache = "On"
+
+# Config.debug is off
+debug = "Off"
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config {
+                cache: Toggle::On,
+                debug: Toggle::Off,
+            }
+        );
+    }
+
+    #[test]
+    fn inline_doc() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// the port to listen
+            #[toml_example(inline_doc, default = 80)]
+            port: usize,
+            /// Config.host is the host name,
+            /// the multi-line doc is placed above
+            #[toml_example(inline_doc)]
+            host: String,
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"port = 80 # the port to listen
+
+# Config.host is the host name,
+# the multi-line doc is placed above
+host = ""
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config {
+                port: 80,
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn skip() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.a is a number
+            a: usize,
+            #[toml_example(skip)]
+            b: usize,
+            #[serde(skip)]
+            c: usize,
+            #[serde(skip_deserializing)]
+            d: usize,
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.a is a number
+a = 0
+
+"#
+        );
+    }
+
+    #[test]
+    fn skip_serializing() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.a is required on deserializing
+            #[serde(skip_serializing)]
+            a: usize,
+            #[serde(default, skip_serializing)]
+            b: usize,
+            #[serde(skip_serializing)]
+            c: Option<usize>,
+            #[serde(default)]
+            #[toml_example(skip_serializing)]
+            d: usize,
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.a is required on deserializing
+a = 0
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config::default()
+        );
+    }
+
+    #[test]
+    fn skip_precedence() {
+        #[derive(Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Custom;
+
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.a is a number
+            a: usize,
+            #[serde(default)]
+            #[toml_example(skip)]
+            b: usize,
+            #[serde(skip)]
+            #[toml_example(require)]
+            c: Option<Custom>,
+            #[serde(default)]
+            #[toml_example(skip, nesting, default = 7)]
+            d: usize,
+        }
+        assert_eq!(
+            Config::toml_example(),
+            r#"# Config.a is a number
+a = 0
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config::default()
+        );
+    }
+
+    #[test]
+    fn phantom_data() {
+        use std::marker::PhantomData;
+
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config<T> {
+            /// Config.a is a number
+            a: usize,
+            #[serde(default)]
+            marker: PhantomData<T>,
+        }
+        assert_eq!(
+            Config::<String>::toml_example(),
+            r#"# Config.a is a number
+a = 0
 
 "#
         );
         assert_eq!(
-            toml::from_str::<Outer>(&Outer::toml_example()).unwrap(),
-            Outer::default()
+            toml::from_str::<Config<String>>(&Config::<String>::toml_example()).unwrap(),
+            Config::default()
         );
     }
 
     #[test]
-    fn optional_nesting_vector() {
-        /// Service with specific port
-        #[derive(TomlExample, Deserialize)]
+    fn bounded_generic() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug, Clone)]
         #[allow(dead_code)]
-        struct Service {
-            /// port should be a number
-            port: usize,
+        struct Inner {
+            /// Inner.a is a number
+            a: usize,
         }
-        #[derive(TomlExample, Deserialize)]
+
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
         #[allow(dead_code)]
-        struct Node {
-            /// Services are running in the node
+        struct Config<T: Clone, U>
+        where
+            U: Default,
+        {
+            /// Config.value is a nesting generic
             #[toml_example(nesting)]
-            services: Option<Vec<Service>>,
+            value: T,
+            #[serde(default)]
+            #[toml_example(skip)]
+            other: U,
         }
+
         assert_eq!(
-            Node::toml_example(),
-            r#"# Services are running in the node
-# Service with specific port
-# [[services]]
-# port should be a number
-# port = 0
+            Config::<Inner, usize>::toml_example(),
+            r#"# Config.value is a nesting generic
+[value]
+# Inner.a is a number
+a = 0
 
 "#
         );
-        assert!(toml::from_str::<Node>(&Node::toml_example()).is_ok());
+        assert_eq!(
+            toml::from_str::<Config<Inner, usize>>(&Config::<Inner, usize>::toml_example())
+                .unwrap(),
+            Config::default()
+        );
     }
 
     #[test]
-    fn optional_nesting_hashmap() {
-        /// Service with specific port
-        #[derive(TomlExample, Deserialize)]
+    fn no_comments() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
         #[allow(dead_code)]
-        struct Service {
-            /// port should be a number
-            port: usize,
+        struct Inner {
+            /// Inner.b is a number
+            b: usize,
         }
-        #[derive(TomlExample, Deserialize)]
+
+        /// Config is a struct with comments
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
         #[allow(dead_code)]
-        struct Node {
-            /// Services are running in the node
+        struct Config {
+            /// Config.color is a hex color
+            #[toml_example(default = "#ffffff")]
+            color: String,
+            /// Config.c is optional
+            c: Option<usize>,
+            /// Config.inner is nesting
             #[toml_example(nesting)]
-            services: Option<HashMap<String, Service>>,
+            inner: Inner,
         }
+
         assert_eq!(
-            Node::toml_example(),
-            r#"# Services are running in the node
-# Service with specific port
-# [services.example]
-# port should be a number
-# port = 0
+            Config::toml_example(),
+            r##"# Config is a struct with comments
+# Config.color is a hex color
+color = "#ffffff"
 
-"#
+# Config.c is optional
+# c = 0
+
+# Config.inner is nesting
+[inner]
+# Inner.b is a number
+b = 0
+
+"##
+        );
+        assert_eq!(
+            Config::toml_example_no_comments(),
+            r##"color = "#ffffff"
+[inner]
+b = 0
+"##
+        );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example_no_comments()).unwrap(),
+            Config {
+                color: "#ffffff".into(),
+                ..Default::default()
+            }
         );
-        assert!(toml::from_str::<Node>(&Node::toml_example()).is_ok());
     }
 
     #[test]
-    fn nesting_hashmap_with_default_name() {
-        /// Service with specific port
-        #[derive(TomlExample, Deserialize)]
-        #[allow(dead_code)]
-        struct Service {
-            /// port should be a number
-            #[toml_example(default = 80)]
-            port: usize,
-        }
-        #[derive(TomlExample, Deserialize)]
+    fn r_sharp_field() {
+        #[derive(TomlExample)]
         #[allow(dead_code)]
-        struct Node {
-            /// Services are running in the node
-            #[toml_example(nesting)]
-            #[toml_example(default = http)]
-            services: HashMap<String, Service>,
+        struct Config {
+            /// Config.type is a number
+            r#type: usize,
         }
         assert_eq!(
-            Node::toml_example(),
-            r#"# Services are running in the node
-# Service with specific port
-[services.http]
-# port should be a number
-port = 80
+            Config::toml_example(),
+            r#"# Config.type is a number
+type = 0
 
 "#
         );
-        assert!(toml::from_str::<Node>(&Node::toml_example()).is_ok());
     }
 
     #[test]
-    fn nesting_hashmap_with_dash_name() {
-        /// Service with specific port
-        #[derive(TomlExample, Deserialize)]
+    fn non_nesting_field_should_be_first() {
+        #[derive(TomlExample)]
         #[allow(dead_code)]
-        struct Service {
-            /// port should be a number
-            #[toml_example(default = 80)]
-            port: usize,
+        struct Foo {
+            a: String,
         }
-        #[derive(TomlExample, Deserialize)]
+
+        #[derive(TomlExample)]
         #[allow(dead_code)]
-        struct Node {
-            /// Services are running in the node
+        struct Bar {
             #[toml_example(nesting)]
-            #[toml_example(default = http.01)]
-            services: HashMap<String, Service>,
+            foo: Foo,
+            b: String,
         }
+
         assert_eq!(
-            Node::toml_example(),
-            r#"# Services are running in the node
-# Service with specific port
-[services.http-01]
-# port should be a number
-port = 80
+            Bar::toml_example(),
+            r#"b = ""
+
+[foo]
+a = ""
 
 "#
         );
-        assert!(toml::from_str::<Node>(&Node::toml_example()).is_ok());
     }
 
     #[test]
-    fn require() {
+    fn adjacent_sections() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Empty {}
+
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Item {
+            a: usize,
+        }
+
         #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
         #[allow(dead_code)]
         struct Config {
-            /// Config.a is an optional number
-            #[toml_example(require)]
-            a: Option<usize>,
-            /// Config.b is an optional string
-            #[toml_example(require)]
-            b: Option<String>,
-            #[toml_example(require)]
-            #[toml_example(default = "third")]
-            c: Option<String>,
+            #[toml_example(nesting)]
+            empty: Empty,
+            #[toml_example(nesting)]
+            item: Item,
+            #[toml_example(nesting)]
+            items: Vec<Item>,
         }
+
         assert_eq!(
             Config::toml_example(),
-            r#"# Config.a is an optional number
-a = 0
+            r#"[empty]
 
-# Config.b is an optional string
-b = ""
+[item]
+a = 0
 
-c = "third"
+[[items]]
+a = 0
 
 "#
         );
+        assert!(!Config::toml_example().contains("\n\n\n"));
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config {
+                items: vec![Item::default()],
+                ..Default::default()
+            }
+        );
     }
 
     #[test]
-    fn skip() {
+    fn only_sections() {
+        /// Item is a config live in Config
         #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
         #[allow(dead_code)]
-        struct Config {
-            /// Config.a is a number
+        struct Item {
+            /// Item.a should be a number
             a: usize,
-            #[toml_example(skip)]
-            b: usize,
-            #[serde(skip)]
-            c: usize,
-            #[serde(skip_deserializing)]
-            d: usize,
         }
+
+        /// Config only has sections
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Config {
+            /// Config.first is the first section
+            #[toml_example(nesting)]
+            first: Item,
+            /// Config.second is the second section
+            #[toml_example(nesting)]
+            second: Item,
+        }
+
         assert_eq!(
             Config::toml_example(),
-            r#"# Config.a is a number
+            r#"# Config only has sections
+# Config.first is the first section
+# Item is a config live in Config
+[first]
+# Item.a should be a number
+a = 0
+
+# Config.second is the second section
+# Item is a config live in Config
+[second]
+# Item.a should be a number
 a = 0
 
 "#
         );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config::default()
+        );
     }
 
     #[test]
-    fn r_sharp_field() {
-        #[derive(TomlExample)]
+    fn sections_inline() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[allow(dead_code)]
+        struct Item {
+            a: usize,
+        }
+
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[toml_example(sections_inline)]
         #[allow(dead_code)]
         struct Config {
-            /// Config.type is a number
-            r#type: usize,
+            /// Config.name should be a string
+            name: String,
+            #[toml_example(nesting)]
+            item: Item,
+            /// Config.extra is optional, which is commented out in the section
+            extra: Option<usize>,
         }
+
         assert_eq!(
             Config::toml_example(),
-            r#"# Config.type is a number
-type = 0
+            r#"# Config.name should be a string
+name = ""
+
+[item]
+a = 0
+
+# Config.extra is optional, which is commented out in the section
+# extra = 0
 
 "#
         );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config::default()
+        );
     }
 
     #[test]
-    fn non_nesting_field_should_be_first() {
-        #[derive(TomlExample)]
+    fn section_example() {
+        /// Server is a config live in Config
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
         #[allow(dead_code)]
-        struct Foo {
-            a: String,
+        struct Server {
+            /// Server.port should be a number
+            port: usize,
+            /// Server.host should be a string
+            host: String,
         }
 
-        #[derive(TomlExample)]
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
         #[allow(dead_code)]
-        struct Bar {
-            #[toml_example(nesting)]
-            foo: Foo,
-            b: String,
+        struct Config {
+            /// Config.server is a curated example
+            #[toml_example(nesting, section_example = "port = 8080\nhost = \"x\"")]
+            server: Server,
+            /// Config.backup is optional
+            #[toml_example(nesting, section_example = "port = 8081\nhost = \"y\"\n")]
+            backup: Option<Server>,
         }
 
         assert_eq!(
-            Bar::toml_example(),
-            r#"b = ""
+            Config::toml_example(),
+            r#"# Config.server is a curated example
+# Server is a config live in Config
+[server]
+port = 8080
+host = "x"
 
-[foo]
-a = ""
+# Config.backup is optional
+# Server is a config live in Config
+# [backup]
+# port = 8081
+# host = "y"
 
 "#
         );
+        assert_eq!(
+            toml::from_str::<Config>(&Config::toml_example()).unwrap(),
+            Config {
+                server: Server {
+                    port: 8080,
+                    host: "x".into(),
+                },
+                backup: None,
+            }
+        );
     }
 
     #[test]
@@ -877,6 +5579,42 @@ a = ""
         );
     }
 
+    #[test]
+    fn rename_all_nesting_map() {
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[serde(rename_all = "camelCase")]
+        #[allow(dead_code)]
+        struct Service {
+            /// Service.max_conn is a number
+            max_conn: usize,
+        }
+
+        #[derive(TomlExample, Deserialize, Default, PartialEq, Debug)]
+        #[serde(rename_all = "kebab-case")]
+        #[allow(dead_code)]
+        struct Node {
+            /// Node.my_services are running in the node
+            #[toml_example(nesting)]
+            my_services: HashMap<String, Service>,
+        }
+
+        assert_eq!(
+            Node::toml_example(),
+            r#"# Node.my_services are running in the node
+[my-services.example]
+# Service.max_conn is a number
+maxConn = 0
+
+"#
+        );
+        assert_eq!(
+            toml::from_str::<Node>(&Node::toml_example()).unwrap(),
+            Node {
+                my_services: HashMap::from([("example".to_string(), Service::default())]),
+            }
+        );
+    }
+
     #[test]
     fn hashset_and_struct() {
         use std::collections::HashMap;
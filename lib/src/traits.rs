@@ -1,13 +1,105 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
 use std::fs::File;
+#[cfg(feature = "std")]
 use std::io::prelude::*;
+#[cfg(feature = "std")]
+use std::path::Path;
 
 pub trait TomlExample {
+    /// the example has section headers, such that it can not be an inline table
+    const HAS_SECTIONS: bool = false;
     /// structure to toml example
     fn toml_example() -> String;
     fn toml_example_with_prefix(label: &str, prefix: &str) -> String;
+    /// structure to toml example in a section, `parents` are the type names of the structs above
+    /// the section, the sections of a struct already in the parents are dropped, such that a
+    /// recursive example, ex: `A -> B -> A`, is not generated infinitely
+    #[doc(hidden)]
+    fn toml_example_in_section(label: &str, prefix: &str, parents: &[&str]) -> String {
+        let _ = parents;
+        Self::toml_example_with_prefix(label, prefix)
+    }
+    /// structure to toml example with the values of the default instance, which is given by the
+    /// parent with `#[toml_example(default)]`
+    fn toml_example_with_default(label: &str, prefix: &str, default: &Self) -> String {
+        let _ = default;
+        Self::toml_example_with_prefix(label, prefix)
+    }
+    /// structure to inline toml value, which is an inline table, or an array for a tuple struct
+    fn toml_example_inline() -> String {
+        crate::utils::inline_table(&Self::toml_example())
+    }
+    /// structure to toml example without comments, only the keys and the sections are kept
+    fn toml_example_no_comments() -> String {
+        crate::utils::strip_comments(&Self::toml_example())
+    }
+    /// structure to toml example with a header, each line of the header is commented, and the
+    /// header is separated from the example by a blank line
+    fn to_toml_example_string_with_header(header: &str) -> String {
+        let mut example: String = header
+            .lines()
+            .map(|l| if l.is_empty() { "#\n".to_string() } else { format!("# {l}\n") })
+            .collect();
+        example.push('\n');
+        example + &Self::toml_example()
+    }
+    /// structure to toml example as front matter, the example is between the `+++` fences, which is
+    /// used by static site generators, ex: Zola, Hugo
+    fn toml_example_front_matter() -> String {
+        format!("+++\n{}\n+++", Self::toml_example().trim_end())
+    }
+    /// structure to toml example as a reference document, every key and section is commented out,
+    /// such that nothing is enabled by default
+    fn toml_example_reference() -> String {
+        crate::utils::comment_out(&Self::toml_example())
+    }
+    /// structure to toml example rendered by the formatter, ex: to customize the comments
+    fn toml_example_with_formatter<F: crate::TomlExampleFormatter>(formatter: &F) -> String {
+        crate::formatter::format_example(&Self::toml_example(), formatter)
+    }
+    /// the top-level keys of the example, which can be used to validate the keys of a config
+    fn toml_example_keys() -> Vec<String> {
+        Vec::new()
+    }
+    /// the top-level keys of the example missing in the current config, which are the new options,
+    /// each key is reported in a line, ex: `+ timeout`
+    #[cfg(feature = "toml")]
+    fn toml_example_diff(current: &str) -> String {
+        crate::utils::diff_keys(&Self::toml_example(), current)
+    }
+    /// write the example to the writer, ex: a file, a buffer or stdout
+    #[cfg(feature = "std")]
+    fn write_toml_example<W: Write>(writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(Self::toml_example().as_bytes())
+    }
+    /// print the example to stdout, ex: for a `--print-config-template` flag of a CLI tool
+    #[cfg(feature = "std")]
+    fn print_toml_example() {
+        print!("{}", Self::toml_example());
+    }
+    #[cfg(feature = "std")]
     fn to_toml_example(file_name: &str) -> std::io::Result<()> {
-        let mut file = File::create(file_name)?;
-        file.write_all(Self::toml_example().as_bytes())?;
-        Ok(())
+        Self::write_toml_example(&mut File::create(file_name)?)
+    }
+    /// write the example to a file with the line ending, ex: `LineEnding::CrLf` for Windows
+    #[cfg(feature = "std")]
+    fn to_toml_example_with_line_ending(
+        file_name: &str,
+        line_ending: crate::LineEnding,
+    ) -> std::io::Result<()> {
+        Self::write_toml_example(&mut crate::utils::LineEndingWriter {
+            inner: File::create(file_name)?,
+            line_ending,
+        })
+    }
+    /// write the example to `<base>.example`, ex: `config.toml.example` for `config.toml`
+    #[cfg(feature = "std")]
+    fn to_toml_example_file<P: AsRef<Path>>(base: P) -> std::io::Result<()> {
+        let mut file_name = base.as_ref().as_os_str().to_owned();
+        file_name.push(".example");
+        Self::write_toml_example(&mut File::create(file_name)?)
     }
 }
@@ -0,0 +1,375 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::Debug;
+
+#[cfg(any(feature = "toml", test))]
+use crate::TomlExample;
+
+/// Comment out each line of the example, the empty lines and the comment lines are kept.
+pub fn comment_out(example: &str) -> String {
+    example
+        .split_inclusive('\n')
+        .map(|l| {
+            if l.trim().is_empty() || l.starts_with('#') {
+                l.to_string()
+            } else {
+                format!("# {l}")
+            }
+        })
+        .collect()
+}
+
+/// Indent the lines after the label of a section with a tab, the lines before the label, ex: the
+/// doc of the struct, are kept.
+pub fn indent_body(section: &str, label: &str) -> String {
+    let Some((head, body)) = section.split_once(label) else {
+        return section.to_string();
+    };
+    let body: String = body
+        .split_inclusive('\n')
+        .map(|l| {
+            if l.trim().is_empty() {
+                l.to_string()
+            } else {
+                format!("\t{l}")
+            }
+        })
+        .collect();
+    format!("{head}{label}{body}")
+}
+
+/// Turn the active `key = value` lines of the example into an inline table, the comments are
+/// dropped, the example should not have a section, which is checked by `nesting = inline` at compile
+/// time, and the keys after the first section header are not included.
+pub fn inline_table(example: &str) -> String {
+    let pairs: Vec<&str> = example
+        .lines()
+        .map(|l| strip_line_comment(l).trim())
+        .filter(|l| !l.is_empty())
+        .take_while(|l| !l.starts_with('['))
+        .collect();
+    if pairs.is_empty() {
+        "{}".to_string()
+    } else {
+        format!("{{ {} }}", pairs.join(", "))
+    }
+}
+
+/// Remove the comments and the empty lines of the example, a `#` inside a quoted string is kept.
+pub fn strip_comments(example: &str) -> String {
+    example
+        .lines()
+        .map(strip_line_comment)
+        .filter(|l| !l.is_empty())
+        .map(|l| format!("{l}\n"))
+        .collect()
+}
+
+fn strip_line_comment(line: &str) -> &str {
+    let mut quote = None;
+    let mut escaped = false;
+    for (i, c) in line.char_indices() {
+        match (quote, c) {
+            (Some('"'), '\\') if !escaped => {
+                escaped = true;
+                continue;
+            }
+            (Some(q), c) if c == q && !escaped => quote = None,
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '#') => return line[..i].trim_end(),
+            _ => (),
+        }
+        escaped = false;
+    }
+    line.trim_end()
+}
+
+/// Render a map as an inline table, the keys are sorted and quoted if they are not bare keys,
+/// and the values are rendered from the `Debug` format with `format_array`, ex: `{ a = "x", b = "y" }`.
+pub fn inline_map<K: ToString, V: Debug>(map: impl IntoIterator<Item = (K, V)>) -> String {
+    let mut pairs: Vec<(String, String)> = map
+        .into_iter()
+        .map(|(k, v)| (k.to_string(), format_array(&format!("{v:?}"), false)))
+        .collect();
+    if pairs.is_empty() {
+        return "{}".to_string();
+    }
+    pairs.sort();
+    let pairs: Vec<String> = pairs
+        .into_iter()
+        .map(|(k, v)| format!("{} = {v}", toml_key(&format_scalar(&format!("{k:?}")))))
+        .collect();
+    format!("{{ {} }}", pairs.join(", "))
+}
+
+/// Render the `Debug` format of a value in toml, the arrays and the tuples are in the array style of
+/// the example, ex: `["a", "b"]` is `[ "a", "b", ]`, or `["a", "b"]` if it is compact, the nested
+/// arrays are rendered as well, a map is an inline table, and the scalars are rendered with
+/// `format_scalar`.
+pub fn format_array(value: &str, compact: bool) -> String {
+    let value = value.trim();
+    let value = match value.strip_prefix("Some(") {
+        Some(inner) if value.ends_with(')') => inner[..inner.len() - 1].trim(),
+        _ => value,
+    };
+    let table = value.starts_with('{') && value.ends_with('}');
+    if !(table
+        || value.starts_with('[') && value.ends_with(']')
+        || value.starts_with('(') && value.ends_with(')'))
+    {
+        return format_scalar(value);
+    }
+    let items = split_items(&value[1..value.len() - 1], ',')
+        .into_iter()
+        .filter(|i| !i.trim().is_empty());
+    if table {
+        let pairs: Vec<String> = items
+            .filter_map(|i| {
+                let (key, value) = i.split_at(split_items(i, ':').first()?.len());
+                let value = value.strip_prefix(':')?;
+                Some(format!("{} = {}", toml_key(&format_scalar(key)), format_array(value, compact)))
+            })
+            .collect();
+        return if pairs.is_empty() {
+            "{}".to_string()
+        } else {
+            format!("{{ {} }}", pairs.join(", "))
+        };
+    }
+    let items: Vec<String> = items.map(|i| format_array(i, compact)).collect();
+    match (compact, items.is_empty()) {
+        (true, _) => format!("[{}]", items.join(", ")),
+        (false, true) => "[ ]".to_string(),
+        (false, false) => format!("[ {}, ]", items.join(", ")),
+    }
+}
+
+/// Render the `Debug` format of a string, a char, a float or a unit variant in toml, the rust
+/// escapes are turned into the toml escapes, ex: `"\0"` is `"\u0000"`, `'x'` is `"x"`, `NaN` is
+/// `nan`, and the variant `Low` is `"Low"`, the other values are kept as they are.
+pub fn format_scalar(value: &str) -> String {
+    let value = value.trim();
+    if value == "NaN" {
+        return "nan".to_string();
+    }
+    let is_variant = value.starts_with(|c: char| c.is_ascii_uppercase())
+        && value.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if is_variant {
+        return format!("{value:?}");
+    }
+    let quoted = value.len() >= 2
+        && (value.starts_with('"') && value.ends_with('"')
+            || value.starts_with('\'') && value.ends_with('\''));
+    if !quoted {
+        return value.to_string();
+    }
+    let mut escaped = "\"".to_string();
+    let mut chars = value[1..value.len() - 1].chars();
+    while let Some(c) = chars.next() {
+        match (c, c == '\\') {
+            // only in a char, a quote in a string is escaped already
+            ('"', _) => escaped.push_str("\\\""),
+            (c, false) => escaped.push(c),
+            (_, true) => match chars.next() {
+                Some('0') => escaped.push_str("\\u0000"),
+                Some('\'') => escaped.push('\''),
+                Some('u') => {
+                    // `\u{1b}` is `\u001B`, the code point beyond 4 digits is `\U`
+                    let hex: String = chars.by_ref().skip(1).take_while(|c| *c != '}').collect();
+                    if hex.len() > 4 {
+                        escaped.push_str(&format!("\\U{:0>8}", hex.to_uppercase()));
+                    } else {
+                        escaped.push_str(&format!("\\u{:0>4}", hex.to_uppercase()));
+                    }
+                }
+                Some(c) => {
+                    escaped.push('\\');
+                    escaped.push(c);
+                }
+                None => escaped.push_str("\\\\"),
+            },
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+/// The bare key if the quoted key only has the characters of a bare key, ex: `"a"` is `a`
+fn toml_key(key: &str) -> String {
+    match key.strip_prefix('"').and_then(|k| k.strip_suffix('"')) {
+        Some(k)
+            if !k.is_empty()
+                && k.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') =>
+        {
+            k.to_string()
+        }
+        _ => key.to_string(),
+    }
+}
+
+/// Split the `Debug` format by `pat`, which is not enclosed in brackets, braces, parentheses, a
+/// string or a char.
+fn split_items(value: &str, pat: char) -> Vec<&str> {
+    let mut items = Vec::new();
+    let (mut depth, mut start, mut quote, mut escaped) = (0, 0, None, false);
+    for (i, c) in value.char_indices() {
+        match (quote, c) {
+            _ if escaped => escaped = false,
+            (Some(_), '\\') => escaped = true,
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => (),
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '[' | '(' | '{') => depth += 1,
+            (None, ']' | ')' | '}') => depth -= 1,
+            (None, c) if c == pat && depth == 0 => {
+                items.push(&value[start..i]);
+                start = i + c.len_utf8();
+            }
+            _ => (),
+        }
+    }
+    items.push(&value[start..]);
+    items
+}
+
+/// Render the `Debug` format of a range as the table of the bounds, which is how serde serializes
+/// it, ex: `0..10` is `{ start = 0, end = 10 }`.
+pub fn format_range(value: &str) -> String {
+    match value.split_once("..=").or_else(|| value.split_once("..")) {
+        Some((start, end)) => format!("{{ start = {start}, end = {end} }}"),
+        None => value.to_string(),
+    }
+}
+
+/// The line ending of the example file, the example is generated with LF.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LineEnding {
+    #[default]
+    Lf,
+    CrLf,
+}
+
+impl LineEnding {
+    /// Translate the LF line endings of the example.
+    pub fn apply(self, example: &str) -> String {
+        match self {
+            LineEnding::Lf => example.to_string(),
+            LineEnding::CrLf => example.replace('\n', "\r\n"),
+        }
+    }
+}
+
+/// The writer translating the LF line endings of the example written into it.
+#[cfg(feature = "std")]
+pub(crate) struct LineEndingWriter<W> {
+    pub(crate) inner: W,
+    pub(crate) line_ending: LineEnding,
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> std::io::Write for LineEndingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match self.line_ending {
+            LineEnding::Lf => self.inner.write_all(buf)?,
+            LineEnding::CrLf => {
+                for line in buf.split_inclusive(|b| *b == b'\n') {
+                    match line.strip_suffix(b"\n") {
+                        Some(line) => {
+                            self.inner.write_all(line)?;
+                            self.inner.write_all(b"\r\n")?;
+                        }
+                        None => self.inner.write_all(line)?,
+                    }
+                }
+            }
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Check the example of `T` round-trips, the example is parsed into `T`, serialized back, and
+/// each value in the example should be the same after the round trip.
+/// The mismatched keys are reported in the error.
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use toml_example::TomlExample;
+/// #[derive(TomlExample, Deserialize, Serialize)]
+/// struct Config {
+///     #[toml_example(default = 7)]
+///     a: usize,
+/// }
+/// toml_example::roundtrip_check::<Config>().unwrap();
+/// ```
+// also in the tests of the crate, where the dev-dependencies provide serde and toml
+#[cfg(any(feature = "toml", test))]
+pub fn roundtrip_check<T>() -> Result<(), String>
+where
+    T: TomlExample + serde::de::DeserializeOwned + serde::Serialize,
+{
+    let example = T::toml_example();
+    let expected: toml::Table = toml::from_str(&example).map_err(|e| e.to_string())?;
+    let parsed: T = toml::from_str(&example).map_err(|e| e.to_string())?;
+    let actual = toml::Table::try_from(&parsed).map_err(|e| e.to_string())?;
+    let mut mismatches = Vec::new();
+    diff_table("", &expected, &actual, &mut mismatches);
+    if mismatches.is_empty() {
+        Ok(())
+    } else {
+        Err(mismatches.join("\n"))
+    }
+}
+
+/// Report the top-level keys of the example missing in the current config, each key is in a line,
+/// ex: `+ timeout`. The config which can not be parsed is reported as an error.
+/// ```rust
+/// assert_eq!(toml_example::diff_keys("a = 0\nb = 0\n", "a = 1\n"), "+ b\n");
+/// ```
+#[cfg(feature = "toml")]
+pub fn diff_keys(example: &str, current: &str) -> String {
+    let example: toml::Table = match toml::from_str(example) {
+        Ok(t) => t,
+        Err(e) => return format!("invalid example: {e}"),
+    };
+    let current: toml::Table = match toml::from_str(current) {
+        Ok(t) => t,
+        Err(e) => return format!("invalid config: {e}"),
+    };
+    example
+        .keys()
+        .filter(|k| !current.contains_key(*k))
+        .map(|k| format!("+ {k}\n"))
+        .collect()
+}
+
+#[cfg(any(feature = "toml", test))]
+fn diff_table(
+    path: &str,
+    expected: &toml::Table,
+    actual: &toml::Table,
+    mismatches: &mut Vec<String>,
+) {
+    for (key, expected_value) in expected.iter() {
+        let key_path = if path.is_empty() {
+            key.to_string()
+        } else {
+            format!("{path}.{key}")
+        };
+        match (expected_value, actual.get(key)) {
+            (toml::Value::Table(e), Some(toml::Value::Table(a))) => {
+                diff_table(&key_path, e, a, mismatches)
+            }
+            (e, Some(a)) if e == a => (),
+            (e, Some(a)) => {
+                mismatches.push(format!("{key_path}: {e} in example, {a} after round trip"))
+            }
+            (e, None) => mismatches.push(format!(
+                "{key_path}: {e} in example, missing after round trip"
+            )),
+        }
+    }
+}
@@ -0,0 +1,10 @@
+use toml_example::TomlExample;
+
+#[derive(TomlExample)]
+struct Config {
+    a: usize,
+    #[toml_example(commented)]
+    c: usize,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use toml_example::TomlExample;
+
+#[derive(TomlExample)]
+struct Config {
+    #[toml_example(default = "hello")]
+    a: usize,
+}
+
+fn main() {}
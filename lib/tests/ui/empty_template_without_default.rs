@@ -0,0 +1,14 @@
+use toml_example::TomlExample;
+
+#[derive(TomlExample)]
+struct Service {
+    port: usize,
+}
+
+#[derive(TomlExample)]
+struct Node {
+    #[toml_example(nesting, empty_template)]
+    services: Vec<Service>,
+}
+
+fn main() {}
@@ -0,0 +1,23 @@
+use toml_example::TomlExample;
+
+#[derive(TomlExample, Debug, Default)]
+struct Retry {
+    retries: usize,
+}
+
+#[derive(TomlExample, Debug)]
+#[toml_example(default)]
+struct Config {
+    #[toml_example(nesting)]
+    retry: Retry,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            retry: Retry { retries: 5 },
+        }
+    }
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use toml_example::TomlExample;
+
+#[derive(Debug, Default)]
+enum Mode {
+    #[default]
+    Fast,
+}
+
+#[derive(TomlExample, Default)]
+#[toml_example(default)]
+struct Config {
+    mode: Mode,
+}
+
+fn main() {}
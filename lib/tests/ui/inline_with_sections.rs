@@ -0,0 +1,23 @@
+use toml_example::TomlExample;
+
+#[derive(TomlExample)]
+struct Tls {
+    cert: String,
+}
+
+#[derive(TomlExample)]
+struct Server {
+    port: usize,
+    #[toml_example(nesting)]
+    tls: Tls,
+}
+
+#[derive(TomlExample)]
+struct Config {
+    #[toml_example(nesting = inline)]
+    server: Server,
+}
+
+fn main() {
+    let _ = Config::toml_example();
+}
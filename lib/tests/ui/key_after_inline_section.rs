@@ -0,0 +1,16 @@
+use toml_example::TomlExample;
+
+#[derive(TomlExample)]
+struct Item {
+    a: usize,
+}
+
+#[derive(TomlExample)]
+#[toml_example(sections_inline)]
+struct Config {
+    #[toml_example(nesting)]
+    item: Item,
+    name: String,
+}
+
+fn main() {}
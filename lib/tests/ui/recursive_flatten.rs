@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use toml_example::TomlExample;
+
+#[derive(TomlExample, Deserialize)]
+struct Node {
+    name: String,
+    #[serde(flatten)]
+    child: Option<Box<Node>>,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use toml_example::TomlExample;
+
+#[derive(TomlExample)]
+struct Node {
+    name: String,
+    #[toml_example(nesting = inline)]
+    child: Option<Box<Self>>,
+}
+
+fn main() {}
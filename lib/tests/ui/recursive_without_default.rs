@@ -0,0 +1,10 @@
+use toml_example::TomlExample;
+
+#[derive(TomlExample)]
+struct Tree {
+    name: String,
+    #[toml_example(nesting)]
+    children: Vec<Tree>,
+}
+
+fn main() {}
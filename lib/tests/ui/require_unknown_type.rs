@@ -0,0 +1,11 @@
+use toml_example::TomlExample;
+
+struct Custom;
+
+#[derive(TomlExample)]
+struct Config {
+    #[toml_example(require)]
+    a: Option<Custom>,
+}
+
+fn main() {}
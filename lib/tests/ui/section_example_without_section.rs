@@ -0,0 +1,14 @@
+use toml_example::TomlExample;
+
+#[derive(TomlExample)]
+struct Server {
+    port: usize,
+}
+
+#[derive(TomlExample)]
+struct Config {
+    #[toml_example(nesting = prefix, section_example = "port = 8080")]
+    server: Server,
+}
+
+fn main() {}
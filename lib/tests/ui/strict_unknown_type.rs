@@ -0,0 +1,11 @@
+use toml_example::TomlExample;
+
+struct Custom;
+
+#[derive(TomlExample)]
+#[toml_example(strict)]
+struct Config {
+    a: Custom,
+}
+
+fn main() {}